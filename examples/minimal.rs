@@ -1,6 +1,7 @@
 //! A minimal example of how to use `leafwing_2d`
 
 use bevy::prelude::*;
+use leafwing_2d::kinematics::{Damping, LinearDamping};
 use leafwing_2d::prelude::*;
 // On 0.6, there is a namespace clash with `bevy_ui::FlexDirection`
 use leafwing_2d::orientation::Direction;
@@ -17,7 +18,6 @@ fn main() {
         .add_system(move_towards_click)
         // Or, we can use the included kinematics to work in terms of velocity and acceleration
         .add_system(accelerate_player)
-        .add_system(drag)
         // Use an AABB to ensure the player doesn't go out of bounds
         .add_system(bound_player)
         .run();
@@ -38,13 +38,16 @@ struct PlayerBundle {
 fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
     commands.spawn_bundle(OrthographicCameraBundle::new_2d());
 
-    commands.spawn_bundle(PlayerBundle {
-        sprite: SpriteBundle {
-            texture: asset_server.load("textures/simplespace/ship_C.png"),
+    commands
+        .spawn_bundle(PlayerBundle {
+            sprite: SpriteBundle {
+                texture: asset_server.load("textures/simplespace/ship_C.png"),
+                ..Default::default()
+            },
             ..Default::default()
-        },
-        ..Default::default()
-    });
+        })
+        // Drag slows the player down when they stop accelerating
+        .insert(LinearDamping(Damping::Quadratic(0.002)));
 }
 
 fn rotate_player(mut query: Query<&mut Rotation, With<Player>>, input: Res<Input<KeyCode>>) {
@@ -72,25 +75,6 @@ fn accelerate_player(
     }
 }
 
-// FIXME: very broken wow
-fn drag(mut query: Query<(&Velocity<F32>, &mut Acceleration<F32>)>, time: Res<Time>) {
-    const DRAG_COEFFICIENT: f32 = 0.2;
-
-    for (velocity, mut acceleration) in query.iter_mut() {
-        // Drag has no effect on objects that aren't moving
-        if let Some(velocity_direction) = velocity.direction() {
-            *acceleration += Acceleration::new(
-                // Standard formula for drag under turbulent conditions
-                DRAG_COEFFICIENT
-                    * velocity.magnitude()
-                    * velocity.magnitude()
-                    * time.delta_seconds(),
-                -velocity_direction,
-            );
-        }
-    }
-}
-
 // FIXME: does not bound correctly
 fn bound_player(mut query: Query<&mut Transform, With<Player>>, windows: Res<Windows>) {
     let mut player_transform = query.single_mut();
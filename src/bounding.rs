@@ -1,8 +1,44 @@
 //! Structs that bound regions that contain [`Positions`](crate::position::Position)
 
 use crate::coordinate::Coordinate;
+use crate::errors::EmptyRegion;
 use crate::position::Position;
 use bevy_ecs::prelude::Component;
+use bevy_math::Vec2;
+
+pub use bvh::BoundingVolumeHierarchy;
+pub use circle::BoundingCircle;
+pub use collider::Collider;
+pub use layers::{CollisionLayer, CollisionLayers};
+#[cfg(feature = "sprite")]
+pub use local::BoundsFromSprite;
+pub use local::LocalBoundingBox;
+pub use polygon::BoundingPolygon;
+pub use proximity::Proximity;
+pub use quadtree::Quadtree;
+pub use raycast::{nearest_ray_hit, Ray, RayHit, Raycast, RaycastQuery};
+pub use response::{CollisionResponse, StaticCollider};
+pub use spatial_hash::SpatialHash;
+pub use spatial_query::SpatialQuery;
+pub use sweep::{ContinuousCollision, Sweep, TimeOfImpact};
+
+pub(crate) use proximity::{contact, Hull, ToHull};
+pub(crate) use response::separation;
+pub(crate) use sweep::sweep_through;
+
+mod bvh;
+mod circle;
+mod collider;
+mod layers;
+mod local;
+mod polygon;
+mod proximity;
+mod quadtree;
+mod raycast;
+mod response;
+mod spatial_hash;
+mod spatial_query;
+mod sweep;
 
 /// A 2D region that could contain a [`Position`]
 pub trait BoundingRegion {
@@ -18,7 +54,7 @@ pub trait BoundingRegion {
     /// Does this region contain the `point`?
     fn contains(&self, position: Position<Self::C>) -> bool;
 
-    /// Does this region intersect with the `other` region of the same type?
+    /// How does this region intersect with the `other` region of the same type?
     fn intersects(&self, other: Self) -> Intersects;
 
     /// Clamp the provided position to the limits of this region, taking the shortest path
@@ -26,13 +62,70 @@ pub trait BoundingRegion {
 }
 
 /// How do two [`BoundingRegions`](BoundingRegion) intersect?
+///
+/// Variants describe the relationship of `self` to `other` in [`BoundingRegion::intersects`].
+/// Containment takes priority: a region that is inside another but touches its edges is contained,
+/// and identical regions contain each other, which is reported as [`Intersects::Contains`].
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::bounding::{AxisAlignedBoundingBox, BoundingRegion, Intersects};
+/// use leafwing_2d::continuous::F32;
+///
+/// let safe_zone = AxisAlignedBoundingBox::<F32>::new(0.0, 10.0, 0.0, 10.0);
+/// let player = AxisAlignedBoundingBox::<F32>::new(2.0, 3.0, 2.0, 4.0);
+/// let ledge = AxisAlignedBoundingBox::<F32>::new(10.0, 15.0, 9.0, 10.0);
+///
+/// assert_eq!(safe_zone.intersects(player.clone()), Intersects::Contains);
+/// assert_eq!(player.intersects(safe_zone.clone()), Intersects::ContainedBy);
+/// assert_eq!(safe_zone.intersects(ledge), Intersects::Touching);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Intersects {
-    /// The regions overlap, including if one region is contained within the other
-    Yes,
+    /// The regions share some area, but neither contains the other
+    Overlapping,
+    /// `self` entirely contains `other`, including its edges
+    Contains,
+    /// `self` is entirely contained within `other`, including its edges
+    ContainedBy,
+    /// Only the edges of the two regions meet: they share no area
+    Touching,
     /// The two regions do not overlap at all
     No,
 }
 
+impl Intersects {
+    /// Do the regions have any points in common, including merely touching edges?
+    #[inline]
+    #[must_use]
+    pub fn is_intersecting(self) -> bool {
+        self != Intersects::No
+    }
+
+    /// Do the regions share some area, rather than merely touching or not meeting at all?
+    #[inline]
+    #[must_use]
+    pub fn is_overlapping(self) -> bool {
+        matches!(
+            self,
+            Intersects::Overlapping | Intersects::Contains | Intersects::ContainedBy
+        )
+    }
+
+    /// The same relationship, described from the perspective of `other` rather than `self`
+    ///
+    /// This swaps [`Intersects::Contains`] and [`Intersects::ContainedBy`].
+    #[inline]
+    #[must_use]
+    pub fn reversed(self) -> Self {
+        match self {
+            Intersects::Contains => Intersects::ContainedBy,
+            Intersects::ContainedBy => Intersects::Contains,
+            other => other,
+        }
+    }
+}
+
 /// A 2-dimensional axis-aligned bounding box with coordinate type C
 ///
 /// # Warning
@@ -46,19 +139,16 @@ pub enum Intersects {
 /// use leafwing_2d::bounding::{AxisAlignedBoundingBox, BoundingRegion};
 /// use leafwing_2d::position::Position;
 ///
-/// let positions: Vec<Position<f32>> = vec![
-///         Position::new(0.0, 0.0),
-///         Position::new(-1.0, 1.0),
+/// use leafwing_2d::continuous::F32;
+///
+/// let positions: Vec<Position<F32>> = vec![
+///         Position::new(2.0, 3.0),
+///         Position::new(-1.0, 5.0),
 ///         Position::new(3.0, 4.0),
 ///         Position::new(-1.0, 17.0),
 ///     ];
 ///
-/// let aabb = AxisAlignedBoundingBox::<f32> {
-///     low_x: -1.0,
-///     low_y: 0.0,
-///     high_x: 3.0,
-///     high_y: 17.0,
-/// };
+/// let aabb = AxisAlignedBoundingBox::<F32>::new(-1.0, 3.0, 3.0, 17.0);
 ///
 /// assert_eq!(aabb, AxisAlignedBoundingBox::draw_around(positions.iter().cloned()));
 ///
@@ -75,13 +165,13 @@ pub enum Intersects {
 /// ```
 #[derive(Debug, Component, Clone, PartialEq, Eq, Default)]
 pub struct AxisAlignedBoundingBox<C: Coordinate> {
-    /// The left extent of the bounding box
+    /// The left extent of the bounding box, its smallest x-coordinate
     pub left: C,
-    /// The top extent of the bounding box
+    /// The right extent of the bounding box, its largest x-coordinate
     pub right: C,
-    /// The bottom extent of the bounding box
+    /// The bottom extent of the bounding box, its smallest y-coordinate
     pub bottom: C,
-    /// The right extent of the bounding box
+    /// The top extent of the bounding box, its largest y-coordinate
     pub top: C,
 }
 
@@ -89,55 +179,49 @@ impl<C: Coordinate> BoundingRegion for AxisAlignedBoundingBox<C> {
     type C = C;
 
     fn vertexes(&self) -> Vec<Position<Self::C>> {
-        vec![
-            self.top_right(),
-            self.bottom_right(),
-            self.bottom_left(),
-            self.top_left(),
-        ]
+        self.corners().to_vec()
     }
 
+    /// If `positions` is empty, a box with no area at the origin is returned.
+    /// Use [`AxisAlignedBoundingBox::try_draw_around`] to handle this case explicitly.
     fn draw_around(positions: impl IntoIterator<Item = Position<Self::C>>) -> Self {
-        let mut aabb = Self {
-            left: C::default(),
-            bottom: C::default(),
-            top: C::default(),
-            right: C::default(),
-        };
-
-        for position in positions.into_iter() {
-            if position.x < aabb.left {
-                aabb.left = position.x;
-            } else if position.x > aabb.top {
-                aabb.top = position.x;
-            }
-
-            if position.y < aabb.bottom {
-                aabb.bottom = position.y;
-            } else if position.y > aabb.right {
-                aabb.right = position.y;
-            }
-        }
-
-        aabb
+        Self::try_draw_around(positions).unwrap_or_default()
     }
 
     fn contains(&self, position: Position<Self::C>) -> bool {
         (self.left <= position.x)
             & (self.bottom <= position.y)
-            & (self.top >= position.x)
-            & (self.right >= position.y)
+            & (self.right >= position.x)
+            & (self.top >= position.y)
     }
 
     fn intersects(&self, other: Self) -> Intersects {
-        if (self.left > other.top)
-            | (other.left > self.top)
-            | (self.bottom > other.right)
-            | (other.bottom > self.right)
+        if (self.left > other.right)
+            | (other.left > self.right)
+            | (self.bottom > other.top)
+            | (other.bottom > self.top)
         {
             Intersects::No
+        } else if (self.left <= other.left)
+            & (self.right >= other.right)
+            & (self.bottom <= other.bottom)
+            & (self.top >= other.top)
+        {
+            Intersects::Contains
+        } else if (other.left <= self.left)
+            & (other.right >= self.right)
+            & (other.bottom <= self.bottom)
+            & (other.top >= self.top)
+        {
+            Intersects::ContainedBy
+        } else if (self.left == other.right)
+            | (other.left == self.right)
+            | (self.bottom == other.top)
+            | (other.bottom == self.top)
+        {
+            Intersects::Touching
         } else {
-            Intersects::Yes
+            Intersects::Overlapping
         }
     }
 
@@ -146,14 +230,14 @@ impl<C: Coordinate> BoundingRegion for AxisAlignedBoundingBox<C> {
 
         if position.x < self.left {
             new_position.x = self.left;
-        } else if position.x > self.top {
-            new_position.x = self.top;
+        } else if position.x > self.right {
+            new_position.x = self.right;
         }
 
         if position.y < self.bottom {
             new_position.y = self.bottom;
-        } else if position.y > self.right {
-            new_position.y = self.right;
+        } else if position.y > self.top {
+            new_position.y = self.top;
         }
 
         new_position
@@ -211,6 +295,57 @@ impl<C: Coordinate> AxisAlignedBoundingBox<C> {
         }
     }
 
+    /// Tightly draws a new bounding box around the provided collection of [`Positions`](Position)
+    ///
+    /// # Errors
+    /// Returns [`EmptyRegion`] if `positions` is empty.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::bounding::AxisAlignedBoundingBox;
+    /// use leafwing_2d::continuous::F32;
+    /// use leafwing_2d::errors::EmptyRegion;
+    /// use leafwing_2d::position::Position;
+    ///
+    /// let aabb = AxisAlignedBoundingBox::<F32>::try_draw_around([
+    ///     Position::new(5.0, 7.0),
+    ///     Position::new(3.0, 10.0),
+    /// ]);
+    /// assert_eq!(aabb, Ok(AxisAlignedBoundingBox::new(3.0, 5.0, 7.0, 10.0)));
+    ///
+    /// assert_eq!(AxisAlignedBoundingBox::<F32>::try_draw_around([]), Err(EmptyRegion));
+    /// ```
+    pub fn try_draw_around(
+        positions: impl IntoIterator<Item = Position<C>>,
+    ) -> Result<Self, EmptyRegion> {
+        let mut positions = positions.into_iter();
+        let first = positions.next().ok_or(EmptyRegion)?;
+
+        Ok(positions.fold(
+            Self {
+                left: first.x,
+                right: first.x,
+                bottom: first.y,
+                top: first.y,
+            },
+            |mut aabb, position| {
+                if position.x < aabb.left {
+                    aabb.left = position.x;
+                } else if position.x > aabb.right {
+                    aabb.right = position.x;
+                }
+
+                if position.y < aabb.bottom {
+                    aabb.bottom = position.y;
+                } else if position.y > aabb.top {
+                    aabb.top = position.y;
+                }
+
+                aabb
+            },
+        ))
+    }
+
     /// Gets the bottom left [`Position`] of this bounding box
     #[inline]
     #[must_use]
@@ -226,7 +361,7 @@ impl<C: Coordinate> AxisAlignedBoundingBox<C> {
     #[must_use]
     pub fn bottom_right(&self) -> Position<C> {
         Position {
-            x: self.top,
+            x: self.right,
             y: self.bottom,
         }
     }
@@ -237,7 +372,7 @@ impl<C: Coordinate> AxisAlignedBoundingBox<C> {
     pub fn top_left(&self) -> Position<C> {
         Position {
             x: self.left,
-            y: self.right,
+            y: self.top,
         }
     }
 
@@ -246,8 +381,341 @@ impl<C: Coordinate> AxisAlignedBoundingBox<C> {
     #[must_use]
     pub fn top_right(&self) -> Position<C> {
         Position {
-            x: self.top,
-            y: self.right,
+            x: self.right,
+            y: self.top,
+        }
+    }
+}
+
+// Geometry helpers
+impl<C: Coordinate> AxisAlignedBoundingBox<C> {
+    /// The distance between the left and right sides of this bounding box
+    #[inline]
+    #[must_use]
+    pub fn width(&self) -> C {
+        self.right - self.left
+    }
+
+    /// The distance between the bottom and top sides of this bounding box
+    #[inline]
+    #[must_use]
+    pub fn height(&self) -> C {
+        self.top - self.bottom
+    }
+
+    /// The area enclosed by this bounding box
+    #[inline]
+    #[must_use]
+    pub fn area(&self) -> C {
+        self.width() * self.height()
+    }
+
+    /// The [`Position`] at the center of this bounding box
+    ///
+    /// For discrete coordinates, this is rounded in the same way as division of `C`.
+    #[inline]
+    #[must_use]
+    pub fn center(&self) -> Position<C> {
+        let two = C::DATA_ONE + C::DATA_ONE;
+
+        Position {
+            x: (self.left + self.right) / two,
+            y: (self.bottom + self.top) / two,
+        }
+    }
+
+    /// Iterates over the corners of this bounding box
+    ///
+    /// The corners are returned in clockwise order, starting from the top right.
+    /// This matches the order of [`BoundingRegion::vertexes`].
+    #[inline]
+    #[must_use]
+    pub fn corners(&self) -> [Position<C>; 4] {
+        [
+            self.top_right(),
+            self.bottom_right(),
+            self.bottom_left(),
+            self.top_left(),
+        ]
+    }
+
+    /// The smallest bounding box that contains both `self` and `other`
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::bounding::AxisAlignedBoundingBox;
+    /// use leafwing_2d::continuous::F32;
+    ///
+    /// let a = AxisAlignedBoundingBox::<F32>::new(0.0, 1.0, 0.0, 1.0);
+    /// let b = AxisAlignedBoundingBox::<F32>::new(3.0, 4.0, -2.0, 0.5);
+    ///
+    /// assert_eq!(a.union(&b), AxisAlignedBoundingBox::new(0.0, 4.0, -2.0, 1.0));
+    /// ```
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        Self {
+            left: partial_min(self.left, other.left),
+            right: partial_max(self.right, other.right),
+            bottom: partial_min(self.bottom, other.bottom),
+            top: partial_max(self.top, other.top),
+        }
+    }
+
+    /// The region where `self` and `other` overlap
+    ///
+    /// Returns `None` if they do not overlap at all.
+    /// Bounding boxes whose edges are merely touching produce a box with zero width or height.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::bounding::AxisAlignedBoundingBox;
+    /// use leafwing_2d::continuous::F32;
+    ///
+    /// let a = AxisAlignedBoundingBox::<F32>::new(0.0, 2.0, 0.0, 2.0);
+    /// let b = AxisAlignedBoundingBox::<F32>::new(1.0, 4.0, -2.0, 1.0);
+    /// let c = AxisAlignedBoundingBox::<F32>::new(3.0, 4.0, 3.0, 4.0);
+    ///
+    /// assert_eq!(a.intersection(&b), Some(AxisAlignedBoundingBox::new(1.0, 2.0, 0.0, 1.0)));
+    /// assert_eq!(a.intersection(&c), None);
+    /// ```
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let left = partial_max(self.left, other.left);
+        let right = partial_min(self.right, other.right);
+        let bottom = partial_max(self.bottom, other.bottom);
+        let top = partial_min(self.top, other.top);
+
+        if (left > right) | (bottom > top) {
+            None
+        } else {
+            Some(Self {
+                left,
+                right,
+                bottom,
+                top,
+            })
+        }
+    }
+
+    /// Grows this bounding box outwards by `margin` on every side
+    #[inline]
+    #[must_use]
+    pub fn expand<T: Into<C>>(&self, margin: T) -> Self {
+        let margin = margin.into();
+
+        Self {
+            left: self.left - margin,
+            right: self.right + margin,
+            bottom: self.bottom - margin,
+            top: self.top + margin,
         }
     }
+
+    /// A copy of this bounding box moved by `offset`, without changing its size
+    ///
+    /// This is equivalent to `self.clone() + offset`.
+    #[inline]
+    #[must_use]
+    pub fn translated(&self, offset: Position<C>) -> Self {
+        self.clone() + offset
+    }
+
+    /// Splits this bounding box into four equally-sized quadrants
+    ///
+    /// The quadrants are returned in clockwise order, starting from the top right.
+    /// This matches the order of [`AxisAlignedBoundingBox::corners`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::bounding::AxisAlignedBoundingBox;
+    /// use leafwing_2d::continuous::F32;
+    ///
+    /// let aabb = AxisAlignedBoundingBox::<F32>::new(0.0, 4.0, 0.0, 2.0);
+    /// let [top_right, bottom_right, bottom_left, top_left] = aabb.quadrants();
+    ///
+    /// assert_eq!(top_right, AxisAlignedBoundingBox::new(2.0, 4.0, 1.0, 2.0));
+    /// assert_eq!(bottom_left, AxisAlignedBoundingBox::new(0.0, 2.0, 0.0, 1.0));
+    /// assert_eq!(top_left.area() + bottom_right.area(), F32(4.0));
+    /// ```
+    #[must_use]
+    pub fn quadrants(&self) -> [Self; 4] {
+        let center = self.center();
+
+        [
+            Self {
+                left: center.x,
+                right: self.right,
+                bottom: center.y,
+                top: self.top,
+            },
+            Self {
+                left: center.x,
+                right: self.right,
+                bottom: self.bottom,
+                top: center.y,
+            },
+            Self {
+                left: self.left,
+                right: center.x,
+                bottom: self.bottom,
+                top: center.y,
+            },
+            Self {
+                left: self.left,
+                right: center.x,
+                bottom: center.y,
+                top: self.top,
+            },
+        ]
+    }
+
+    /// Creates a new AABB centered on `position` that matches the size of a sprite
+    ///
+    /// Sprite sizes are measured in the units of [`Transform`](bevy_transform::components::Transform),
+    /// such as the `custom_size` field of a `Sprite`, and are converted into `C`.
+    #[inline]
+    #[must_use]
+    pub fn from_sprite_size(position: Position<C>, size: Vec2) -> Self {
+        let half_width = C::from(size.x / 2.);
+        let half_height = C::from(size.y / 2.);
+
+        Self::from_size(position, half_width, half_height)
+    }
+
+    /// The size of a sprite that would exactly fill this bounding box
+    ///
+    /// This is measured in the units of [`Transform`](bevy_transform::components::Transform).
+    #[inline]
+    #[must_use]
+    pub fn sprite_size(&self) -> Vec2 {
+        Vec2::new(self.width().into(), self.height().into())
+    }
+}
+
+/// The lesser of two partially ordered values, preferring `a` if they are not comparable
+#[inline]
+fn partial_min<C: Coordinate>(a: C, b: C) -> C {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+/// The greater of two partially ordered values, preferring `a` if they are not comparable
+#[inline]
+fn partial_max<C: Coordinate>(a: C, b: C) -> C {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
+mod ops {
+    use super::AxisAlignedBoundingBox;
+    use crate::coordinate::Coordinate;
+    use crate::position::Position;
+    use core::ops::{Add, AddAssign, Sub, SubAssign};
+
+    impl<C: Coordinate> Add<Position<C>> for AxisAlignedBoundingBox<C> {
+        type Output = AxisAlignedBoundingBox<C>;
+
+        fn add(self, rhs: Position<C>) -> Self::Output {
+            AxisAlignedBoundingBox {
+                left: self.left + rhs.x,
+                right: self.right + rhs.x,
+                bottom: self.bottom + rhs.y,
+                top: self.top + rhs.y,
+            }
+        }
+    }
+
+    impl<C: Coordinate> Sub<Position<C>> for AxisAlignedBoundingBox<C> {
+        type Output = AxisAlignedBoundingBox<C>;
+
+        fn sub(self, rhs: Position<C>) -> Self::Output {
+            AxisAlignedBoundingBox {
+                left: self.left - rhs.x,
+                right: self.right - rhs.x,
+                bottom: self.bottom - rhs.y,
+                top: self.top - rhs.y,
+            }
+        }
+    }
+
+    impl<C: Coordinate> AddAssign<Position<C>> for AxisAlignedBoundingBox<C> {
+        fn add_assign(&mut self, rhs: Position<C>) {
+            self.left += rhs.x;
+            self.right += rhs.x;
+            self.bottom += rhs.y;
+            self.top += rhs.y;
+        }
+    }
+
+    impl<C: Coordinate> SubAssign<Position<C>> for AxisAlignedBoundingBox<C> {
+        fn sub_assign(&mut self, rhs: Position<C>) {
+            self.left -= rhs.x;
+            self.right -= rhs.x;
+            self.bottom -= rhs.y;
+            self.top -= rhs.y;
+        }
+    }
+}
+
+mod conversions {
+    use super::AxisAlignedBoundingBox;
+    use crate::coordinate::Coordinate;
+    use crate::position::Position;
+    use bevy_math::Rect;
+
+    impl<C: Coordinate> From<Position<C>> for AxisAlignedBoundingBox<C> {
+        /// Creates a bounding box with no area, which contains only `position`
+        fn from(position: Position<C>) -> AxisAlignedBoundingBox<C> {
+            AxisAlignedBoundingBox {
+                left: position.x,
+                right: position.x,
+                bottom: position.y,
+                top: position.y,
+            }
+        }
+    }
+
+    impl<C: Coordinate> From<AxisAlignedBoundingBox<C>> for Rect<f32> {
+        fn from(aabb: AxisAlignedBoundingBox<C>) -> Rect<f32> {
+            Rect {
+                left: aabb.left.into(),
+                right: aabb.right.into(),
+                top: aabb.top.into(),
+                bottom: aabb.bottom.into(),
+            }
+        }
+    }
+
+    impl<C: Coordinate> From<Rect<f32>> for AxisAlignedBoundingBox<C> {
+        fn from(rect: Rect<f32>) -> AxisAlignedBoundingBox<C> {
+            AxisAlignedBoundingBox {
+                left: rect.left.into(),
+                right: rect.right.into(),
+                bottom: rect.bottom.into(),
+                top: rect.top.into(),
+            }
+        }
+    }
+}
+
+/// The union of all of the bounding components of an entity, if it has any
+pub(crate) fn combined_bounds<C: Coordinate>(
+    maybe_aabb: Option<&AxisAlignedBoundingBox<C>>,
+    maybe_circle: Option<&BoundingCircle<C>>,
+    maybe_polygon: Option<&BoundingPolygon<C>>,
+    maybe_collider: Option<&Collider<C>>,
+) -> Option<AxisAlignedBoundingBox<C>> {
+    maybe_aabb
+        .cloned()
+        .into_iter()
+        .chain(maybe_circle.map(BoundingCircle::bounding_box))
+        .chain(maybe_polygon.map(BoundingPolygon::bounding_box))
+        .chain(maybe_collider.map(Collider::bounding_box))
+        .reduce(|a, b| a.union(&b))
 }
@@ -0,0 +1,405 @@
+use super::raycast::cast_ray_through_box;
+use super::{AxisAlignedBoundingBox, CollisionLayers, Ray, RayHit};
+use crate::coordinate::Coordinate;
+use crate::position::Position;
+use bevy_ecs::entity::Entity;
+use bevy_math::Vec2;
+use std::collections::HashMap;
+
+/// A binary tree of nested [`AxisAlignedBoundingBox`]es, for fast queries against many mostly-static entities
+///
+/// Build this once from your level geometry with [`BoundingVolumeHierarchy::build`].
+/// When entities move, [`BoundingVolumeHierarchy::refit`] grows or shrinks the boxes above them;
+/// this is cheap, but the tree becomes less efficient the further entities move from where they were built,
+/// so rebuild it after large changes.
+///
+/// When inserted as a resource, [`TwoDPlugin`](crate::plugin::TwoDPlugin) refits the tree
+/// whenever the [`Position`] or bounding components of an entity within it change,
+/// and keeps the [`CollisionLayers`] used to filter its queries up to date.
+///
+/// # Example
+/// ```rust
+/// use bevy::ecs::entity::Entity;
+/// use leafwing_2d::bounding::{AxisAlignedBoundingBox, BoundingVolumeHierarchy, Ray};
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::orientation::Direction;
+/// use leafwing_2d::position::Position;
+///
+/// let walls = (0..10).map(|i| {
+///     let position = Position::new(i as f32 * 10.0, 0.0);
+///     (Entity::from_raw(i), AxisAlignedBoundingBox::from_size(position, 1.0, 1.0))
+/// });
+/// let bvh = BoundingVolumeHierarchy::<F32>::build(walls);
+///
+/// assert_eq!(bvh.query_point(Position::new(30.5, 0.5)), vec![Entity::from_raw(3)]);
+///
+/// let ray = Ray::new(Position::new(25.0, 0.0), Direction::EAST);
+/// let (entity, hit) = bvh.cast_ray(ray).unwrap();
+/// assert_eq!(entity, Entity::from_raw(3));
+/// assert_eq!(hit.distance, F32(4.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct BoundingVolumeHierarchy<C: Coordinate> {
+    nodes: Vec<BvhNode<C>>,
+    root: Option<usize>,
+    leaves: HashMap<Entity, usize>,
+    layers: HashMap<Entity, CollisionLayers>,
+}
+
+#[derive(Debug, Clone)]
+struct BvhNode<C: Coordinate> {
+    aabb: AxisAlignedBoundingBox<C>,
+    parent: Option<usize>,
+    kind: BvhNodeKind,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum BvhNodeKind {
+    Leaf(Entity),
+    Branch(usize, usize),
+}
+
+impl<C: Coordinate> Default for BoundingVolumeHierarchy<C> {
+    fn default() -> Self {
+        Self {
+            nodes: Vec::new(),
+            root: None,
+            leaves: HashMap::default(),
+            layers: HashMap::default(),
+        }
+    }
+}
+
+impl<C: Coordinate> BoundingVolumeHierarchy<C> {
+    /// Builds a balanced tree over the provided entities and their bounding boxes
+    ///
+    /// If an entity is provided more than once, only its last bounding box is used.
+    #[must_use]
+    pub fn build(entities: impl IntoIterator<Item = (Entity, AxisAlignedBoundingBox<C>)>) -> Self {
+        let mut items: Vec<(Entity, AxisAlignedBoundingBox<C>)> = entities
+            .into_iter()
+            .collect::<HashMap<_, _>>()
+            .into_iter()
+            .collect();
+        // Keep the shape of the tree deterministic
+        items.sort_unstable_by_key(|&(entity, _)| entity);
+
+        let mut bvh = Self::default();
+        if !items.is_empty() {
+            bvh.root = Some(bvh.build_node(&mut items, None));
+        }
+        bvh
+    }
+
+    /// Recursively splits `items` in half along their longest axis, returning the index of the new node
+    fn build_node(
+        &mut self,
+        items: &mut [(Entity, AxisAlignedBoundingBox<C>)],
+        parent: Option<usize>,
+    ) -> usize {
+        let index = self.nodes.len();
+
+        if let [(entity, aabb)] = items {
+            self.nodes.push(BvhNode {
+                aabb: aabb.clone(),
+                parent,
+                kind: BvhNodeKind::Leaf(*entity),
+            });
+            self.leaves.insert(*entity, index);
+            return index;
+        }
+
+        let aabb = items
+            .iter()
+            .map(|(_, aabb)| aabb.clone())
+            .reduce(|a, b| a.union(&b))
+            .expect("Branches always have at least two items.");
+
+        let centers: Vec<Vec2> = items.iter().map(|(_, aabb)| aabb.center().into()).collect();
+        let min = centers.iter().copied().reduce(Vec2::min).unwrap();
+        let max = centers.iter().copied().reduce(Vec2::max).unwrap();
+        let axis = if max.x - min.x >= max.y - min.y { 0 } else { 1 };
+
+        items.sort_by(|(_, a), (_, b)| {
+            let a: Vec2 = a.center().into();
+            let b: Vec2 = b.center().into();
+            a[axis].total_cmp(&b[axis])
+        });
+
+        // Reserve our slot before our children claim theirs
+        self.nodes.push(BvhNode {
+            aabb,
+            parent,
+            kind: BvhNodeKind::Branch(0, 0),
+        });
+
+        let (low, high) = items.split_at_mut(items.len() / 2);
+        let left = self.build_node(low, Some(index));
+        let right = self.build_node(high, Some(index));
+        self.nodes[index].kind = BvhNodeKind::Branch(left, right);
+
+        index
+    }
+
+    /// The number of entities stored
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.leaves.len()
+    }
+
+    /// Are there no entities stored?
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.leaves.is_empty()
+    }
+
+    /// Iterates over every entity in the tree, in no particular order
+    pub fn entities(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.leaves.keys().copied()
+    }
+
+    /// The bounding box currently stored for `entity`, if any
+    #[inline]
+    #[must_use]
+    pub fn get(&self, entity: Entity) -> Option<&AxisAlignedBoundingBox<C>> {
+        self.leaves
+            .get(&entity)
+            .map(|&index| &self.nodes[index].aabb)
+    }
+
+    /// The bounding box containing every entity in the tree, if any
+    #[inline]
+    #[must_use]
+    pub fn bounds(&self) -> Option<&AxisAlignedBoundingBox<C>> {
+        self.root.map(|root| &self.nodes[root].aabb)
+    }
+
+    /// The [`CollisionLayers`] of `entity`, which default to [`CollisionLayers::ALL`]
+    #[inline]
+    #[must_use]
+    pub fn layers(&self, entity: Entity) -> CollisionLayers {
+        self.layers.get(&entity).copied().unwrap_or_default()
+    }
+
+    /// Sets the [`CollisionLayers`] of `entity`, which are used to filter queries
+    ///
+    /// Returns `false` if the entity is not part of this tree.
+    pub fn set_layers(&mut self, entity: Entity, layers: CollisionLayers) -> bool {
+        if self.leaves.contains_key(&entity) {
+            self.layers.insert(entity, layers);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Updates the bounding box of `entity`, and resizes every box above it to match
+    ///
+    /// Returns `false` if the entity is not part of this tree.
+    pub fn refit(&mut self, entity: Entity, aabb: AxisAlignedBoundingBox<C>) -> bool {
+        let mut index = match self.leaves.get(&entity) {
+            Some(&index) => index,
+            None => return false,
+        };
+
+        self.nodes[index].aabb = aabb;
+
+        while let Some(parent) = self.nodes[index].parent {
+            if let BvhNodeKind::Branch(left, right) = self.nodes[parent].kind {
+                self.nodes[parent].aabb = self.nodes[left].aabb.union(&self.nodes[right].aabb);
+            }
+            index = parent;
+        }
+
+        true
+    }
+
+    /// All entities whose bounding box overlaps `region`, sorted
+    ///
+    /// Entities are found regardless of their [`CollisionLayers`].
+    #[must_use]
+    pub fn query_region(&self, region: &AxisAlignedBoundingBox<C>) -> Vec<Entity> {
+        self.query_region_matching(region, |_| true)
+    }
+
+    /// All entities that interact with `layers` whose bounding box overlaps `region`, sorted
+    #[must_use]
+    pub fn query_region_with_layers(
+        &self,
+        region: &AxisAlignedBoundingBox<C>,
+        layers: &CollisionLayers,
+    ) -> Vec<Entity> {
+        self.query_region_matching(region, |entity| self.layers(entity).interacts_with(layers))
+    }
+
+    /// All entities that pass `filter` whose bounding box overlaps `region`, sorted
+    fn query_region_matching(
+        &self,
+        region: &AxisAlignedBoundingBox<C>,
+        filter: impl Fn(Entity) -> bool,
+    ) -> Vec<Entity> {
+        let mut found: Vec<Entity> = self
+            .leaves_where(|aabb| aabb.intersection(region).is_some())
+            .map(|(entity, _)| entity)
+            .filter(|&entity| filter(entity))
+            .collect();
+
+        found.sort_unstable();
+        found
+    }
+
+    /// All entities whose bounding box contains `position`, sorted
+    ///
+    /// Entities are found regardless of their [`CollisionLayers`].
+    #[must_use]
+    pub fn query_point(&self, position: Position<C>) -> Vec<Entity> {
+        self.query_region(&AxisAlignedBoundingBox::from(position))
+    }
+
+    /// All entities that interact with `layers` whose bounding box contains `position`, sorted
+    #[must_use]
+    pub fn query_point_with_layers(
+        &self,
+        position: Position<C>,
+        layers: &CollisionLayers,
+    ) -> Vec<Entity> {
+        self.query_region_with_layers(&AxisAlignedBoundingBox::from(position), layers)
+    }
+
+    /// Casts the `ray` against the bounding box of every entity, returning the nearest entity struck
+    ///
+    /// Entities are found regardless of their [`CollisionLayers`].
+    #[must_use]
+    pub fn cast_ray(&self, ray: Ray<C>) -> Option<(Entity, RayHit<C>)> {
+        self.cast_ray_matching(ray, |_| true)
+    }
+
+    /// Casts the `ray` against the bounding box of every entity that interacts with `layers`,
+    /// returning the nearest entity struck
+    #[must_use]
+    pub fn cast_ray_with_layers(
+        &self,
+        ray: Ray<C>,
+        layers: &CollisionLayers,
+    ) -> Option<(Entity, RayHit<C>)> {
+        self.cast_ray_matching(ray, |entity| self.layers(entity).interacts_with(layers))
+    }
+
+    /// Casts the `ray` against the bounding box of every entity that passes `filter`,
+    /// returning the nearest entity struck
+    fn cast_ray_matching(
+        &self,
+        ray: Ray<C>,
+        filter: impl Fn(Entity) -> bool,
+    ) -> Option<(Entity, RayHit<C>)> {
+        let mut best: Option<(Entity, RayHit<C>)> = None;
+        let mut stack: Vec<usize> = self.root.into_iter().collect();
+
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            let hit = match cast_ray_through(&node.aabb, ray) {
+                Some(hit) => hit,
+                None => continue,
+            };
+
+            // Nothing inside this node can be closer than the node itself
+            if matches!(best, Some((_, best_hit)) if best_hit.distance <= hit.distance) {
+                continue;
+            }
+
+            match node.kind {
+                BvhNodeKind::Leaf(entity) => {
+                    if filter(entity) {
+                        best = Some((entity, hit));
+                    }
+                }
+                BvhNodeKind::Branch(left, right) => stack.extend([left, right]),
+            }
+        }
+
+        best
+    }
+
+    /// Casts the `ray` against the bounding box of every entity, returning all entities struck
+    ///
+    /// The hits are sorted by distance, from nearest to farthest.
+    /// Entities are found regardless of their [`CollisionLayers`].
+    #[must_use]
+    pub fn cast_ray_all(&self, ray: Ray<C>) -> Vec<(Entity, RayHit<C>)> {
+        self.cast_ray_all_matching(ray, |_| true)
+    }
+
+    /// Casts the `ray` against the bounding box of every entity that interacts with `layers`,
+    /// returning all entities struck
+    ///
+    /// The hits are sorted by distance, from nearest to farthest.
+    #[must_use]
+    pub fn cast_ray_all_with_layers(
+        &self,
+        ray: Ray<C>,
+        layers: &CollisionLayers,
+    ) -> Vec<(Entity, RayHit<C>)> {
+        self.cast_ray_all_matching(ray, |entity| self.layers(entity).interacts_with(layers))
+    }
+
+    /// Casts the `ray` against the bounding box of every entity that passes `filter`,
+    /// returning all entities struck, sorted by distance
+    fn cast_ray_all_matching(
+        &self,
+        ray: Ray<C>,
+        filter: impl Fn(Entity) -> bool,
+    ) -> Vec<(Entity, RayHit<C>)> {
+        let mut hits: Vec<(Entity, RayHit<C>)> = self
+            .leaves_where(|aabb| cast_ray_through(aabb, ray).is_some())
+            .filter(|&(entity, _)| filter(entity))
+            .filter_map(|(entity, aabb)| Some((entity, cast_ray_through(aabb, ray)?)))
+            .collect();
+
+        hits.sort_by(|(_, a), (_, b)| {
+            a.distance
+                .partial_cmp(&b.distance)
+                .unwrap_or(core::cmp::Ordering::Equal)
+        });
+        hits
+    }
+
+    /// Every leaf whose bounding box, and the bounding box of all of its ancestors, satisfies `predicate`
+    fn leaves_where<'a>(
+        &'a self,
+        predicate: impl Fn(&AxisAlignedBoundingBox<C>) -> bool + 'a,
+    ) -> impl Iterator<Item = (Entity, &'a AxisAlignedBoundingBox<C>)> + 'a {
+        let mut stack: Vec<usize> = self.root.into_iter().collect();
+
+        std::iter::from_fn(move || {
+            while let Some(index) = stack.pop() {
+                let node = &self.nodes[index];
+
+                if !predicate(&node.aabb) {
+                    continue;
+                }
+
+                match node.kind {
+                    BvhNodeKind::Leaf(entity) => return Some((entity, &node.aabb)),
+                    BvhNodeKind::Branch(left, right) => stack.extend([left, right]),
+                }
+            }
+
+            None
+        })
+    }
+}
+
+/// Casts the `ray` against `aabb`
+#[inline]
+fn cast_ray_through<C: Coordinate>(
+    aabb: &AxisAlignedBoundingBox<C>,
+    ray: Ray<C>,
+) -> Option<RayHit<C>> {
+    cast_ray_through_box(
+        ray,
+        Vec2::new(aabb.left.into(), aabb.bottom.into()),
+        Vec2::new(aabb.right.into(), aabb.top.into()),
+    )
+}
@@ -0,0 +1,135 @@
+use super::{AxisAlignedBoundingBox, BoundingRegion, Intersects};
+use crate::coordinate::Coordinate;
+use crate::position::Position;
+use bevy_ecs::prelude::Component;
+use bevy_math::Vec2;
+
+/// A 2-dimensional circle with coordinate type C
+///
+/// # Warning
+/// When constructing this type, ensure that `radius` >= [`Coordinate::ZERO`].
+/// Prefer the `new` method when possible (i.e., in non-const contexts)
+/// for better ergonomics and checks.
+///
+/// # Examples
+/// ```rust
+/// use leafwing_2d::bounding::{BoundingCircle, BoundingRegion};
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::position::Position;
+///
+/// let circle = BoundingCircle::<F32>::new(Position::new(1.0, 1.0), 2.0);
+///
+/// assert!(circle.contains(Position::new(1.0, 3.0)));
+/// assert!(!circle.contains(Position::new(3.0, 3.0)));
+///
+/// let outlier = Position::new(1.0, 42.0);
+/// assert_eq!(circle.clamp(outlier), Position::new(1.0, 3.0));
+/// ```
+#[derive(Debug, Component, Clone, PartialEq, Default)]
+pub struct BoundingCircle<C: Coordinate> {
+    /// The center of the circle
+    pub center: Position<C>,
+    /// The distance from the center to the edge of the circle
+    pub radius: C,
+}
+
+impl<C: Coordinate> BoundingRegion for BoundingCircle<C> {
+    type C = C;
+
+    /// Circles have no vertexes, so this always returns an empty [`Vec`]
+    fn vertexes(&self) -> Vec<Position<Self::C>> {
+        Vec::new()
+    }
+
+    fn draw_around(positions: impl IntoIterator<Item = Position<Self::C>>) -> Self {
+        let positions: Vec<Vec2> = positions.into_iter().map(Vec2::from).collect();
+
+        if positions.is_empty() {
+            return Self::default();
+        }
+
+        // Centering the circle on the midpoint of the extremes keeps the radius reasonably tight
+        let min = positions.iter().copied().reduce(Vec2::min).unwrap();
+        let max = positions.iter().copied().reduce(Vec2::max).unwrap();
+        let center = (min + max) / 2.;
+
+        let radius = positions
+            .iter()
+            .map(|&position| position.distance(center))
+            .fold(0., f32::max);
+
+        Self {
+            center: center.into(),
+            radius: C::from(radius),
+        }
+    }
+
+    fn contains(&self, position: Position<Self::C>) -> bool {
+        let radius: f32 = self.radius.into();
+
+        self.center_vec2().distance_squared(position.into()) <= radius * radius
+    }
+
+    fn intersects(&self, other: Self) -> Intersects {
+        let self_radius: f32 = self.radius.into();
+        let other_radius: f32 = other.radius.into();
+        let combined_radius = self_radius + other_radius;
+        let distance_squared = self.center_vec2().distance_squared(other.center_vec2());
+
+        // One circle is inside the other when the distance between centers is at most the difference in radii
+        let radius_difference = self_radius - other_radius;
+        let nested = distance_squared <= radius_difference * radius_difference;
+
+        if distance_squared > combined_radius * combined_radius {
+            Intersects::No
+        } else if nested && radius_difference >= 0. {
+            Intersects::Contains
+        } else if nested {
+            Intersects::ContainedBy
+        } else if distance_squared == combined_radius * combined_radius {
+            Intersects::Touching
+        } else {
+            Intersects::Overlapping
+        }
+    }
+
+    fn clamp(&self, position: Position<Self::C>) -> Position<Self::C> {
+        if self.contains(position) {
+            return position;
+        }
+
+        let center = self.center_vec2();
+        let offset = Vec2::from(position) - center;
+        let radius: f32 = self.radius.into();
+
+        (center + offset.normalize() * radius).into()
+    }
+}
+
+impl<C: Coordinate> BoundingCircle<C> {
+    /// Creates a new circle from its `center` and `radius`
+    ///
+    /// # Panics
+    /// `radius` must be greater than or equal to [`Coordinate::ZERO`].
+    #[inline]
+    #[must_use]
+    pub fn new<T: Into<C>>(center: Position<C>, radius: T) -> Self {
+        let radius = radius.into();
+
+        assert!(radius >= C::ZERO);
+
+        Self { center, radius }
+    }
+
+    /// The smallest [`AxisAlignedBoundingBox`] that contains this circle
+    #[inline]
+    #[must_use]
+    pub fn bounding_box(&self) -> AxisAlignedBoundingBox<C> {
+        AxisAlignedBoundingBox::from_size(self.center, self.radius, self.radius)
+    }
+
+    #[inline]
+    pub(crate) fn center_vec2(&self) -> Vec2 {
+        self.center.into()
+    }
+}
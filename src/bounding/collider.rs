@@ -0,0 +1,128 @@
+use super::proximity::{relationship, Hull, ToHull};
+use super::{
+    AxisAlignedBoundingBox, BoundingCircle, BoundingPolygon, BoundingRegion, Intersects, Ray,
+    RayHit, Raycast,
+};
+use crate::coordinate::Coordinate;
+use crate::position::Position;
+use bevy_ecs::prelude::Component;
+
+/// Any one of the built-in bounding shapes
+///
+/// Unlike the individual shapes, colliders of different kinds can be intersected with each other.
+/// This lets entities with differently-shaped bounds share a single component and be queried together.
+/// [`Collider`] components are considered alongside the individual shape components
+/// by collision events, spatial indexes, raycasts and picking.
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::bounding::{
+///     AxisAlignedBoundingBox, BoundingCircle, BoundingRegion, Collider, Intersects,
+/// };
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::position::Position;
+///
+/// let floor: Collider<F32> = AxisAlignedBoundingBox::new(-10.0, 10.0, -1.0, 0.0).into();
+/// let ball: Collider<F32> = BoundingCircle::new(Position::new(0.0, 1.0), 1.0).into();
+/// let pebble: Collider<F32> = BoundingCircle::new(Position::new(0.0, -0.5), 0.25).into();
+///
+/// assert_eq!(ball.intersects(floor.clone()), Intersects::Touching);
+/// assert_eq!(floor.intersects(pebble), Intersects::Contains);
+/// ```
+#[derive(Debug, Component, Clone, PartialEq)]
+pub enum Collider<C: Coordinate> {
+    /// An [`AxisAlignedBoundingBox`]
+    Aabb(AxisAlignedBoundingBox<C>),
+    /// A [`BoundingCircle`]
+    Circle(BoundingCircle<C>),
+    /// A [`BoundingPolygon`]
+    Polygon(BoundingPolygon<C>),
+}
+
+impl<C: Coordinate> BoundingRegion for Collider<C> {
+    type C = C;
+
+    fn vertexes(&self) -> Vec<Position<Self::C>> {
+        match self {
+            Collider::Aabb(aabb) => aabb.vertexes(),
+            Collider::Circle(circle) => circle.vertexes(),
+            Collider::Polygon(polygon) => polygon.vertexes(),
+        }
+    }
+
+    /// Draws a [`BoundingPolygon`] around the `positions`, which is the tightest built-in shape
+    fn draw_around(positions: impl IntoIterator<Item = Position<Self::C>>) -> Self {
+        Collider::Polygon(BoundingPolygon::draw_around(positions))
+    }
+
+    fn contains(&self, position: Position<Self::C>) -> bool {
+        match self {
+            Collider::Aabb(aabb) => aabb.contains(position),
+            Collider::Circle(circle) => circle.contains(position),
+            Collider::Polygon(polygon) => polygon.contains(position),
+        }
+    }
+
+    /// Colliders of any kind can be intersected with each other
+    fn intersects(&self, other: Self) -> Intersects {
+        relationship(&self.hull(), &other.hull())
+    }
+
+    fn clamp(&self, position: Position<Self::C>) -> Position<Self::C> {
+        match self {
+            Collider::Aabb(aabb) => aabb.clamp(position),
+            Collider::Circle(circle) => circle.clamp(position),
+            Collider::Polygon(polygon) => polygon.clamp(position),
+        }
+    }
+}
+
+impl<C: Coordinate> Raycast for Collider<C> {
+    fn cast_ray(&self, ray: Ray<C>) -> Option<RayHit<C>> {
+        match self {
+            Collider::Aabb(aabb) => aabb.cast_ray(ray),
+            Collider::Circle(circle) => circle.cast_ray(ray),
+            Collider::Polygon(polygon) => polygon.cast_ray(ray),
+        }
+    }
+}
+
+impl<C: Coordinate> Collider<C> {
+    /// The smallest [`AxisAlignedBoundingBox`] that contains this collider
+    #[must_use]
+    pub fn bounding_box(&self) -> AxisAlignedBoundingBox<C> {
+        match self {
+            Collider::Aabb(aabb) => aabb.clone(),
+            Collider::Circle(circle) => circle.bounding_box(),
+            Collider::Polygon(polygon) => polygon.bounding_box(),
+        }
+    }
+}
+
+impl<C: Coordinate> ToHull for Collider<C> {
+    fn hull(&self) -> Hull {
+        match self {
+            Collider::Aabb(aabb) => aabb.hull(),
+            Collider::Circle(circle) => circle.hull(),
+            Collider::Polygon(polygon) => polygon.hull(),
+        }
+    }
+}
+
+impl<C: Coordinate> From<AxisAlignedBoundingBox<C>> for Collider<C> {
+    fn from(aabb: AxisAlignedBoundingBox<C>) -> Collider<C> {
+        Collider::Aabb(aabb)
+    }
+}
+
+impl<C: Coordinate> From<BoundingCircle<C>> for Collider<C> {
+    fn from(circle: BoundingCircle<C>) -> Collider<C> {
+        Collider::Circle(circle)
+    }
+}
+
+impl<C: Coordinate> From<BoundingPolygon<C>> for Collider<C> {
+    fn from(polygon: BoundingPolygon<C>) -> Collider<C> {
+        Collider::Polygon(polygon)
+    }
+}
@@ -0,0 +1,163 @@
+use bevy_ecs::prelude::Component;
+
+/// Controls which entities can collide with, or be found by queries against, each other
+///
+/// Each entity is a member of some layers, and filters for the layers it wishes to interact with.
+/// Two entities interact only if each one is a member of a layer that the other filters for.
+/// Entities without this component are treated as [`CollisionLayers::ALL`].
+///
+/// Layers are stored as bitmasks, with up to 32 layers available.
+/// For more readable code, define your layers with an enum that implements [`CollisionLayer`].
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::bounding::{CollisionLayer, CollisionLayers};
+///
+/// #[derive(Clone, Copy)]
+/// enum Layer {
+///     Player,
+///     Enemy,
+///     PlayerBullet,
+/// }
+///
+/// impl CollisionLayer for Layer {
+///     fn index(self) -> u32 {
+///         self as u32
+///     }
+/// }
+///
+/// let player = CollisionLayers::from_layers([Layer::Player], [Layer::Enemy]);
+/// let enemy = CollisionLayers::from_layers([Layer::Enemy], [Layer::Player, Layer::PlayerBullet]);
+/// let bullet = CollisionLayers::from_layers([Layer::PlayerBullet], [Layer::Enemy]);
+///
+/// assert!(player.interacts_with(&enemy));
+/// assert!(bullet.interacts_with(&enemy));
+/// // Players can't shoot themselves
+/// assert!(!bullet.interacts_with(&player));
+/// ```
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CollisionLayers {
+    /// The layers that this entity belongs to
+    pub memberships: u32,
+    /// The layers that this entity can interact with
+    pub filters: u32,
+}
+
+impl CollisionLayers {
+    /// A member of every layer, which interacts with every layer
+    pub const ALL: CollisionLayers = CollisionLayers {
+        memberships: u32::MAX,
+        filters: u32::MAX,
+    };
+
+    /// A member of no layers, which interacts with nothing
+    pub const NONE: CollisionLayers = CollisionLayers {
+        memberships: 0,
+        filters: 0,
+    };
+
+    /// Creates a new set of collision layers from the raw `memberships` and `filters` bitmasks
+    #[inline]
+    #[must_use]
+    pub const fn new(memberships: u32, filters: u32) -> Self {
+        Self {
+            memberships,
+            filters,
+        }
+    }
+
+    /// Creates a new set of collision layers from the named layers this entity belongs to and interacts with
+    #[must_use]
+    pub fn from_layers<L: CollisionLayer>(
+        memberships: impl IntoIterator<Item = L>,
+        filters: impl IntoIterator<Item = L>,
+    ) -> Self {
+        Self {
+            memberships: memberships
+                .into_iter()
+                .fold(0, |mask, layer| mask | layer.mask()),
+            filters: filters
+                .into_iter()
+                .fold(0, |mask, layer| mask | layer.mask()),
+        }
+    }
+
+    /// Can this entity interact with `other`?
+    ///
+    /// This is symmetric: each entity must filter for a layer that the other is a member of.
+    #[inline]
+    #[must_use]
+    pub const fn interacts_with(&self, other: &CollisionLayers) -> bool {
+        (self.memberships & other.filters != 0) && (other.memberships & self.filters != 0)
+    }
+
+    /// Is this entity a member of `layer`?
+    #[inline]
+    #[must_use]
+    pub fn contains_membership(&self, layer: impl CollisionLayer) -> bool {
+        self.memberships & layer.mask() != 0
+    }
+
+    /// Does this entity interact with `layer`?
+    #[inline]
+    #[must_use]
+    pub fn contains_filter(&self, layer: impl CollisionLayer) -> bool {
+        self.filters & layer.mask() != 0
+    }
+
+    /// Adds `layer` to the layers this entity belongs to
+    #[inline]
+    #[must_use]
+    pub fn with_membership(mut self, layer: impl CollisionLayer) -> Self {
+        self.memberships |= layer.mask();
+        self
+    }
+
+    /// Removes `layer` from the layers this entity belongs to
+    #[inline]
+    #[must_use]
+    pub fn without_membership(mut self, layer: impl CollisionLayer) -> Self {
+        self.memberships &= !layer.mask();
+        self
+    }
+
+    /// Adds `layer` to the layers this entity interacts with
+    #[inline]
+    #[must_use]
+    pub fn with_filter(mut self, layer: impl CollisionLayer) -> Self {
+        self.filters |= layer.mask();
+        self
+    }
+
+    /// Removes `layer` from the layers this entity interacts with
+    #[inline]
+    #[must_use]
+    pub fn without_filter(mut self, layer: impl CollisionLayer) -> Self {
+        self.filters &= !layer.mask();
+        self
+    }
+}
+
+impl Default for CollisionLayers {
+    fn default() -> Self {
+        CollisionLayers::ALL
+    }
+}
+
+/// A named layer for use with [`CollisionLayers`], typically implemented by a fieldless enum
+pub trait CollisionLayer: Copy {
+    /// The index of the bit that represents this layer, which must be less than 32
+    fn index(self) -> u32;
+
+    /// The bitmask that represents this layer
+    ///
+    /// # Panics
+    /// Panics if [`CollisionLayer::index`] is 32 or greater.
+    #[inline]
+    fn mask(self) -> u32 {
+        let index = self.index();
+        assert!(index < u32::BITS);
+
+        1 << index
+    }
+}
@@ -0,0 +1,111 @@
+use super::AxisAlignedBoundingBox;
+use crate::coordinate::Coordinate;
+use crate::orientation::Rotation;
+use crate::position::Position;
+use crate::scale::Scale;
+use bevy_ecs::prelude::Component;
+use bevy_math::{Quat, Vec2};
+
+/// An [`AxisAlignedBoundingBox`] measured relative to the entity's [`Position`]
+///
+/// When used with [`TwoDPlugin`](crate::plugin::TwoDPlugin),
+/// the entity's world-space [`AxisAlignedBoundingBox`] component is recomputed from this each frame,
+/// accounting for its [`Position`], [`Rotation`] and [`Scale`].
+/// Rotated boxes are bounded by the smallest axis-aligned box that contains them.
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::bounding::{AxisAlignedBoundingBox, LocalBoundingBox};
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::position::Position;
+/// use leafwing_2d::scale::Scale;
+///
+/// let local = LocalBoundingBox::<F32>::from_size(2.0, 1.0);
+///
+/// let world = local.world_bounds(Position::new(10.0, 10.0), None, Some(&Scale(2.0)));
+/// assert_eq!(world, AxisAlignedBoundingBox::new(6.0, 14.0, 8.0, 12.0));
+/// ```
+#[derive(Debug, Component, Clone, PartialEq, Default)]
+pub struct LocalBoundingBox<C: Coordinate>(pub AxisAlignedBoundingBox<C>);
+
+impl<C: Coordinate> LocalBoundingBox<C> {
+    /// Creates a new local bounding box centered on the entity's [`Position`]
+    ///
+    /// # Panics
+    /// `half_width` and `half_height` must be greater than or equal to [`Coordinate::ZERO`].
+    #[inline]
+    #[must_use]
+    pub fn from_size<T: Into<C>>(half_width: T, half_height: T) -> Self {
+        Self(AxisAlignedBoundingBox::from_size(
+            Position::default(),
+            half_width,
+            half_height,
+        ))
+    }
+
+    /// Computes the world-space [`AxisAlignedBoundingBox`] of an entity at `position`
+    ///
+    /// The local box is first scaled and then rotated around the entity's origin,
+    /// before being moved to `position`.
+    /// A negative [`Scale`] mirrors the box through the entity's origin.
+    #[must_use]
+    pub fn world_bounds(
+        &self,
+        position: Position<C>,
+        rotation: Option<&Rotation>,
+        scale: Option<&Scale<C>>,
+    ) -> AxisAlignedBoundingBox<C> {
+        let local = match scale {
+            // A negative scale mirrors the box, swapping its opposite edges
+            Some(scale) if C::from(scale.0) < C::ZERO => AxisAlignedBoundingBox {
+                left: self.0.right * scale.0,
+                right: self.0.left * scale.0,
+                bottom: self.0.top * scale.0,
+                top: self.0.bottom * scale.0,
+            },
+            Some(scale) => AxisAlignedBoundingBox {
+                left: self.0.left * scale.0,
+                right: self.0.right * scale.0,
+                bottom: self.0.bottom * scale.0,
+                top: self.0.top * scale.0,
+            },
+            None => self.0.clone(),
+        };
+
+        let rotation = match rotation {
+            Some(&rotation) if rotation != Rotation::NORTH => rotation,
+            // Skip the round trip through floats when we can
+            _ => return local + position,
+        };
+
+        let quat = Quat::from(rotation);
+        let low = Vec2::new(local.left.into(), local.bottom.into());
+        let high = Vec2::new(local.right.into(), local.top.into());
+
+        let (min, max) = [
+            low,
+            Vec2::new(high.x, low.y),
+            high,
+            Vec2::new(low.x, high.y),
+        ]
+        .into_iter()
+        .map(|corner| quat.mul_vec3(corner.extend(0.)).truncate())
+        .fold(
+            (Vec2::splat(f32::INFINITY), Vec2::splat(f32::NEG_INFINITY)),
+            |(min, max), corner| (min.min(corner), max.max(corner)),
+        );
+
+        AxisAlignedBoundingBox::<C>::new(min.x, max.x, min.y, max.y) + position
+    }
+}
+
+/// Derives the entity's [`LocalBoundingBox`] from the size of its `Sprite`
+///
+/// The sprite's `custom_size` is used if set; otherwise the dimensions of its texture are used once it has loaded.
+/// The [`Scale`] and [`Rotation`] of the entity are then applied as usual when computing its [`AxisAlignedBoundingBox`].
+///
+/// The entity must also have a [`LocalBoundingBox`] and an [`AxisAlignedBoundingBox`].
+/// Requires [`TwoDPlugin`](crate::plugin::TwoDPlugin) and the `sprite` feature.
+#[cfg(feature = "sprite")]
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BoundsFromSprite;
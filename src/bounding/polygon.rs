@@ -0,0 +1,232 @@
+use super::{AxisAlignedBoundingBox, BoundingRegion, Intersects};
+use crate::coordinate::Coordinate;
+use crate::position::Position;
+use bevy_ecs::prelude::Component;
+use bevy_math::Vec2;
+
+/// A 2-dimensional convex polygon with coordinate type C
+///
+/// The vertexes are stored in counter-clockwise order.
+/// Concave inputs are replaced by their convex hull, which is always a valid bound for them.
+///
+/// # Examples
+/// ```rust
+/// use leafwing_2d::bounding::{BoundingPolygon, BoundingRegion};
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::position::Position;
+///
+/// let triangle = BoundingPolygon::<F32>::new([
+///     Position::new(0.0, 0.0),
+///     Position::new(4.0, 0.0),
+///     Position::new(0.0, 4.0),
+/// ]);
+///
+/// assert!(triangle.contains(Position::new(1.0, 1.0)));
+/// assert!(!triangle.contains(Position::new(3.0, 3.0)));
+///
+/// assert_eq!(triangle.clamp(Position::new(3.0, 3.0)), Position::new(2.0, 2.0));
+/// ```
+#[derive(Debug, Component, Clone, PartialEq, Default)]
+pub struct BoundingPolygon<C: Coordinate> {
+    pub(crate) vertexes: Vec<Position<C>>,
+}
+
+impl<C: Coordinate> BoundingRegion for BoundingPolygon<C> {
+    type C = C;
+
+    fn vertexes(&self) -> Vec<Position<Self::C>> {
+        self.vertexes.clone()
+    }
+
+    fn draw_around(positions: impl IntoIterator<Item = Position<Self::C>>) -> Self {
+        let mut points: Vec<Vec2> = positions.into_iter().map(Vec2::from).collect();
+        points.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+        points.dedup();
+
+        if points.len() < 3 {
+            return Self {
+                vertexes: points.into_iter().map(Position::from).collect(),
+            };
+        }
+
+        // Andrew's monotone chain: build the lower and upper hulls separately
+        let mut hull: Vec<Vec2> = Vec::with_capacity(points.len() + 1);
+        for pass in [points.clone(), points.into_iter().rev().collect()] {
+            let start = hull.len();
+            for point in pass {
+                while hull.len() >= start + 2
+                    && cross(hull[hull.len() - 2], hull[hull.len() - 1], point) <= 0.
+                {
+                    hull.pop();
+                }
+                hull.push(point);
+            }
+            // The last point of each pass is the first point of the next
+            hull.pop();
+        }
+
+        Self {
+            vertexes: hull.into_iter().map(Position::from).collect(),
+        }
+    }
+
+    fn contains(&self, position: Position<Self::C>) -> bool {
+        if self.vertexes.is_empty() {
+            return false;
+        }
+
+        let point = Vec2::from(position);
+
+        if self.vertexes.len() < 3 {
+            return self.closest_boundary_point(point).distance_squared(point) <= f32::EPSILON;
+        }
+
+        self.edges()
+            .all(|(start, end)| cross(start, end, point) >= 0.)
+    }
+
+    fn intersects(&self, other: Self) -> Intersects {
+        if self.vertexes.is_empty() || other.vertexes.is_empty() {
+            return Intersects::No;
+        }
+
+        let self_points = self.vertex_vec2s();
+        let other_points = other.vertex_vec2s();
+
+        let mut axes = self.edge_normals().chain(other.edge_normals()).peekable();
+
+        // Two single points have no edges to test against
+        if axes.peek().is_none() {
+            return if self_points[0] == other_points[0] {
+                Intersects::Contains
+            } else {
+                Intersects::No
+            };
+        }
+
+        let mut touching = false;
+        for axis in axes {
+            let (self_min, self_max) = project(&self_points, axis);
+            let (other_min, other_max) = project(&other_points, axis);
+
+            if (self_max < other_min) | (other_max < self_min) {
+                return Intersects::No;
+            }
+
+            touching |= (self_max == other_min) | (other_max == self_min);
+        }
+
+        if other.vertexes.iter().all(|&vertex| self.contains(vertex)) {
+            Intersects::Contains
+        } else if self.vertexes.iter().all(|&vertex| other.contains(vertex)) {
+            Intersects::ContainedBy
+        } else if touching {
+            Intersects::Touching
+        } else {
+            Intersects::Overlapping
+        }
+    }
+
+    fn clamp(&self, position: Position<Self::C>) -> Position<Self::C> {
+        if self.vertexes.is_empty() || self.contains(position) {
+            return position;
+        }
+
+        self.closest_boundary_point(position.into()).into()
+    }
+}
+
+impl<C: Coordinate> BoundingPolygon<C> {
+    /// Creates a new convex polygon that tightly bounds the provided `vertexes`
+    #[inline]
+    #[must_use]
+    pub fn new(vertexes: impl IntoIterator<Item = Position<C>>) -> Self {
+        Self::draw_around(vertexes)
+    }
+
+    /// The smallest [`AxisAlignedBoundingBox`] that contains this polygon
+    ///
+    /// Polygons without any vertexes produce the default (empty) bounding box.
+    #[must_use]
+    pub fn bounding_box(&self) -> AxisAlignedBoundingBox<C> {
+        let points = self.vertex_vec2s();
+
+        match (
+            points.iter().copied().reduce(Vec2::min),
+            points.iter().copied().reduce(Vec2::max),
+        ) {
+            (Some(min), Some(max)) => AxisAlignedBoundingBox::new(min.x, max.x, min.y, max.y),
+            _ => AxisAlignedBoundingBox::default(),
+        }
+    }
+
+    /// The vertexes of this polygon, converted to [`Vec2`]
+    pub(crate) fn vertex_vec2s(&self) -> Vec<Vec2> {
+        self.vertexes.iter().map(|&vertex| vertex.into()).collect()
+    }
+
+    /// Each edge of this polygon as a `(start, end)` pair, in counter-clockwise order
+    pub(crate) fn edges(&self) -> impl Iterator<Item = (Vec2, Vec2)> + '_ {
+        let n = self.vertexes.len();
+
+        (0..n).map(move |i| (self.vertexes[i].into(), self.vertexes[(i + 1) % n].into()))
+    }
+
+    /// The outward-facing unit normals of each non-degenerate edge
+    fn edge_normals(&self) -> impl Iterator<Item = Vec2> + '_ {
+        self.edges()
+            .filter(|(start, end)| start != end)
+            .map(|(start, end)| outward_normal(start, end))
+    }
+
+    /// The point on the edges of this polygon that is closest to `point`
+    fn closest_boundary_point(&self, point: Vec2) -> Vec2 {
+        self.edges()
+            .map(|(start, end)| closest_point_on_segment(start, end, point))
+            .min_by(|a, b| {
+                a.distance_squared(point)
+                    .total_cmp(&b.distance_squared(point))
+            })
+            .unwrap_or(point)
+    }
+}
+
+/// The z-component of the cross product of `a -> b` and `a -> c`
+///
+/// Positive if `c` is to the left of the line through `a` and `b`.
+#[inline]
+pub(crate) fn cross(a: Vec2, b: Vec2, c: Vec2) -> f32 {
+    (b - a).perp_dot(c - a)
+}
+
+/// The outward-facing unit normal of a counter-clockwise edge
+#[inline]
+pub(crate) fn outward_normal(start: Vec2, end: Vec2) -> Vec2 {
+    let edge = end - start;
+    Vec2::new(edge.y, -edge.x).normalize()
+}
+
+/// The minimum and maximum extent of `points` along `axis`
+#[inline]
+pub(crate) fn project(points: &[Vec2], axis: Vec2) -> (f32, f32) {
+    points
+        .iter()
+        .map(|point| point.dot(axis))
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), value| {
+            (min.min(value), max.max(value))
+        })
+}
+
+/// The point on the segment between `start` and `end` that is closest to `point`
+#[inline]
+pub(crate) fn closest_point_on_segment(start: Vec2, end: Vec2, point: Vec2) -> Vec2 {
+    let edge = end - start;
+    let length_squared = edge.length_squared();
+
+    if length_squared == 0. {
+        return start;
+    }
+
+    let t = ((point - start).dot(edge) / length_squared).clamp(0., 1.);
+    start + edge * t
+}
@@ -0,0 +1,329 @@
+use super::polygon::{closest_point_on_segment, outward_normal, project};
+use super::{
+    AxisAlignedBoundingBox, BoundingCircle, BoundingPolygon, BoundingRegion, Collider, Intersects,
+};
+use crate::coordinate::Coordinate;
+use crate::position::Position;
+use bevy_math::Vec2;
+
+/// Measures how far a [`BoundingRegion`] is from another region or a [`Position`]
+///
+/// This is implemented between every pair of built-in bounding shapes (including [`Collider`]),
+/// and between each shape and a [`Position`].
+/// Polygons without any vertexes are treated as infinitely far away from everything.
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::bounding::{AxisAlignedBoundingBox, BoundingCircle, Proximity};
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::position::Position;
+///
+/// let guard = BoundingCircle::<F32>::new(Position::new(0.0, 0.0), 1.0);
+/// let wall = AxisAlignedBoundingBox::<F32>::new(3.0, 5.0, -1.0, 1.0);
+///
+/// assert_eq!(guard.signed_distance(&wall), F32(2.0));
+/// assert_eq!(guard.closest_point(&wall), Position::new(1.0, 0.0));
+/// assert_eq!(wall.closest_point(&Position::new(0.0, 0.5)), Position::new(3.0, 0.5));
+///
+/// // Overlapping regions have a negative distance, and can be pushed apart
+/// let crate_ = AxisAlignedBoundingBox::<F32>::new(4.5, 6.0, -1.0, 1.0);
+/// assert_eq!(crate_.signed_distance(&wall), F32(-0.5));
+/// assert_eq!(crate_.minimum_translation(&wall), Some(Position::new(0.5, 0.0)));
+/// ```
+pub trait Proximity<Other>: BoundingRegion {
+    /// The distance between the closest points of `self` and `other`
+    ///
+    /// If the two overlap, this is negative, and its magnitude is how deeply they are interpenetrating.
+    /// Regions that are merely touching have a distance of zero.
+    #[must_use]
+    fn signed_distance(&self, other: &Other) -> Self::C;
+
+    /// The [`Position`] within `self` that is closest to `other`
+    ///
+    /// If the two overlap, this is the point of `self` that ends up touching `other`
+    /// after `self` is moved by its [`Proximity::minimum_translation`].
+    #[must_use]
+    fn closest_point(&self, other: &Other) -> Position<Self::C>;
+
+    /// The shortest offset that `self` must be moved by so that it no longer overlaps `other`
+    ///
+    /// After this offset is applied, the two will be touching.
+    /// Returns `None` if they do not overlap, including when they are merely touching.
+    #[must_use]
+    fn minimum_translation(&self, other: &Other) -> Option<Position<Self::C>>;
+
+    /// How does this region intersect with `other`, which may be a different kind of shape?
+    ///
+    /// This matches [`BoundingRegion::intersects`] when both are the same kind of shape.
+    #[must_use]
+    fn intersects_with(&self, other: &Other) -> Intersects;
+}
+
+/// A convex core, inflated outwards by `radius`
+///
+/// Every built-in bounding shape and [`Position`] can be described this way,
+/// which lets them all be measured against each other in the same way.
+pub(crate) struct Hull {
+    /// The vertexes of the core, in counter-clockwise order
+    points: Vec<Vec2>,
+    radius: f32,
+}
+
+/// Converts a shape into its [`Hull`]
+pub(crate) trait ToHull {
+    fn hull(&self) -> Hull;
+}
+
+impl<C: Coordinate> ToHull for AxisAlignedBoundingBox<C> {
+    fn hull(&self) -> Hull {
+        Hull {
+            points: [
+                self.bottom_left(),
+                self.bottom_right(),
+                self.top_right(),
+                self.top_left(),
+            ]
+            .into_iter()
+            .map(Vec2::from)
+            .collect(),
+            radius: 0.,
+        }
+    }
+}
+
+impl<C: Coordinate> ToHull for BoundingCircle<C> {
+    fn hull(&self) -> Hull {
+        Hull {
+            points: vec![self.center_vec2()],
+            radius: self.radius.into(),
+        }
+    }
+}
+
+impl<C: Coordinate> ToHull for BoundingPolygon<C> {
+    fn hull(&self) -> Hull {
+        Hull {
+            points: self.vertex_vec2s(),
+            radius: 0.,
+        }
+    }
+}
+
+impl<C: Coordinate> ToHull for Position<C> {
+    fn hull(&self) -> Hull {
+        Hull {
+            points: vec![(*self).into()],
+            radius: 0.,
+        }
+    }
+}
+
+impl Hull {
+    /// Each edge of the core as a `(start, end)` pair
+    ///
+    /// A core with a single point has a single edge with no length.
+    fn edges(&self) -> impl Iterator<Item = (Vec2, Vec2)> + '_ {
+        let n = self.points.len();
+
+        (0..n).map(move |i| (self.points[i], self.points[(i + 1) % n]))
+    }
+
+    /// The outward-facing unit normals of each non-degenerate edge of the core
+    fn normals(&self) -> impl Iterator<Item = Vec2> + '_ {
+        self.edges()
+            .filter(|(start, end)| start != end)
+            .map(|(start, end)| outward_normal(start, end))
+    }
+
+    /// The minimum and maximum extent of this hull along `axis`, including its radius
+    fn project(&self, axis: Vec2) -> (f32, f32) {
+        let (min, max) = project(&self.points, axis);
+
+        (min - self.radius, max + self.radius)
+    }
+
+    /// The point of this hull that lies furthest along `direction`
+    fn support(&self, direction: Vec2) -> Vec2 {
+        let furthest = self
+            .points
+            .iter()
+            .copied()
+            .reduce(|best, point| {
+                if point.dot(direction) > best.dot(direction) {
+                    point
+                } else {
+                    best
+                }
+            })
+            .unwrap_or_default();
+
+        furthest + direction * self.radius
+    }
+}
+
+/// How two [`Hulls`](Hull) are positioned relative to each other
+pub(crate) struct Contact {
+    /// The distance between the hulls, which is negative if they overlap
+    pub(crate) distance: f32,
+    /// The point on the first hull that is closest to the second
+    pub(crate) closest_point: Vec2,
+    /// The shortest offset that separates the first hull from the second, if they overlap
+    pub(crate) translation: Option<Vec2>,
+}
+
+/// The pair of points on the cores of `a` and `b` that are closest to each other
+///
+/// This is only meaningful when the cores do not overlap.
+fn closest_core_points(a: &Hull, b: &Hull) -> (Vec2, Vec2) {
+    let a_to_b = a.points.iter().flat_map(|&point| {
+        b.edges()
+            .map(move |(start, end)| (point, closest_point_on_segment(start, end, point)))
+    });
+    let b_to_a = b.points.iter().flat_map(|&point| {
+        a.edges()
+            .map(move |(start, end)| (closest_point_on_segment(start, end, point), point))
+    });
+
+    a_to_b
+        .chain(b_to_a)
+        .min_by(|(a1, b1), (a2, b2)| {
+            a1.distance_squared(*b1)
+                .total_cmp(&a2.distance_squared(*b2))
+        })
+        .unwrap_or_default()
+}
+
+/// Measures the [`Contact`] between `a` and `b`, using the separating axis theorem
+///
+/// Returns `None` if either hull has no points.
+pub(crate) fn contact(a: &Hull, b: &Hull) -> Option<Contact> {
+    if a.points.is_empty() || b.points.is_empty() {
+        return None;
+    }
+
+    let (core_a, core_b) = closest_core_points(a, b);
+    let offset = core_b - core_a;
+
+    // The direction between the closest points is needed for rounded shapes, whose edges are curved
+    let mut axes: Vec<Vec2> = a.normals().chain(b.normals()).collect();
+    axes.extend(offset.try_normalize());
+    if axes.is_empty() {
+        // Two points at the same location: any axis will do
+        axes.push(Vec2::X);
+    }
+
+    let mut deepest: Option<(f32, Vec2)> = None;
+    for axis in axes {
+        let (a_min, a_max) = a.project(axis);
+        let (b_min, b_max) = b.project(axis);
+
+        // How far `a` would need to travel along the negative and positive direction of this axis
+        let negative = a_max - b_min;
+        let positive = b_max - a_min;
+        let overlap = negative.min(positive);
+
+        if overlap <= 0. {
+            // The cores cannot overlap, so the distance between them is exact
+            return Some(Contact {
+                distance: offset.length() - a.radius - b.radius,
+                closest_point: core_a + offset.normalize_or_zero() * a.radius,
+                translation: None,
+            });
+        }
+
+        if deepest.map_or(true, |(depth, _)| overlap < depth) {
+            let translation = if negative < positive {
+                -axis * negative
+            } else {
+                axis * positive
+            };
+            deepest = Some((overlap, translation));
+        }
+    }
+
+    let (depth, translation) = deepest?;
+
+    Some(Contact {
+        distance: -depth,
+        closest_point: a.support(-translation.normalize_or_zero()),
+        translation: Some(translation),
+    })
+}
+
+/// How `a` intersects `b`, as described by [`Intersects`]
+pub(crate) fn relationship(a: &Hull, b: &Hull) -> Intersects {
+    let distance = match contact(a, b) {
+        Some(contact) => contact.distance,
+        None => return Intersects::No,
+    };
+
+    if distance > 0. {
+        Intersects::No
+    } else if encloses(a, b) {
+        Intersects::Contains
+    } else if encloses(b, a) {
+        Intersects::ContainedBy
+    } else if distance == 0. {
+        Intersects::Touching
+    } else {
+        Intersects::Overlapping
+    }
+}
+
+/// Does `outer` entirely contain `inner`, including its edges?
+///
+/// As both hulls are convex, it is enough to check that each vertex of the inner core
+/// lies at least as deep inside `outer` as the radius of `inner`.
+fn encloses(outer: &Hull, inner: &Hull) -> bool {
+    inner.points.iter().all(|&point| {
+        let point = Hull {
+            points: vec![point],
+            radius: 0.,
+        };
+
+        contact(outer, &point).map_or(false, |contact| -contact.distance >= inner.radius)
+    })
+}
+
+/// Implements [`Proximity`] between the `shape` and each of the `others`
+macro_rules! impl_proximity {
+    ($shape:ident => $($other:ident),*) => {
+        $(
+            impl<C: Coordinate> Proximity<$other<C>> for $shape<C> {
+                fn signed_distance(&self, other: &$other<C>) -> C {
+                    contact(&self.hull(), &other.hull())
+                        .map_or(C::MAX, |contact| C::from(contact.distance))
+                }
+
+                fn closest_point(&self, other: &$other<C>) -> Position<C> {
+                    contact(&self.hull(), &other.hull())
+                        .map(|contact| contact.closest_point.into())
+                        .unwrap_or_default()
+                }
+
+                fn minimum_translation(&self, other: &$other<C>) -> Option<Position<C>> {
+                    contact(&self.hull(), &other.hull())?
+                        .translation
+                        .map(Position::from)
+                }
+
+                fn intersects_with(&self, other: &$other<C>) -> Intersects {
+                    relationship(&self.hull(), &other.hull())
+                }
+            }
+        )*
+    };
+}
+
+impl_proximity!(
+    AxisAlignedBoundingBox => AxisAlignedBoundingBox, BoundingCircle, BoundingPolygon, Collider, Position
+);
+impl_proximity!(
+    BoundingCircle => AxisAlignedBoundingBox, BoundingCircle, BoundingPolygon, Collider, Position
+);
+impl_proximity!(
+    BoundingPolygon => AxisAlignedBoundingBox, BoundingCircle, BoundingPolygon, Collider, Position
+);
+impl_proximity!(
+    Collider => AxisAlignedBoundingBox, BoundingCircle, BoundingPolygon, Collider, Position
+);
@@ -0,0 +1,432 @@
+use super::{AxisAlignedBoundingBox, CollisionLayers};
+use crate::coordinate::Coordinate;
+use crate::position::Position;
+use bevy_ecs::entity::Entity;
+use bevy_math::Vec2;
+use std::collections::HashMap;
+
+/// A spatial index that recursively subdivides space into quarters where entities are dense
+///
+/// Unlike [`SpatialHash`](super::SpatialHash), this adapts to uneven distributions of entities:
+/// each node splits into four children once it holds more than `capacity` entities,
+/// until `max_depth` is reached.
+/// Entities that straddle the boundary between children are kept in their parent.
+/// Entities outside of the root bounds are stored in the root node.
+///
+/// When inserted as a resource, [`TwoDPlugin`](crate::plugin::TwoDPlugin) keeps this up to date
+/// with the [`AxisAlignedBoundingBox`], [`BoundingCircle`](super::BoundingCircle), [`BoundingPolygon`](super::BoundingPolygon),
+/// [`Collider`](super::Collider) and [`CollisionLayers`] of each entity whose [`Position`] or bounds have changed.
+///
+/// # Example
+/// ```rust
+/// use bevy::ecs::entity::Entity;
+/// use leafwing_2d::bounding::{AxisAlignedBoundingBox, Quadtree};
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::position::Position;
+///
+/// let world_bounds = AxisAlignedBoundingBox::new(-100.0, 100.0, -100.0, 100.0);
+/// let mut quadtree = Quadtree::<F32>::new(world_bounds, 6, 4);
+///
+/// for i in 0..20 {
+///     let position = Position::new(i as f32 * 5.0 - 50.0, 0.0);
+///     quadtree.insert(Entity::from_raw(i), AxisAlignedBoundingBox::from_size(position, 1.0, 1.0));
+/// }
+///
+/// assert_eq!(quadtree.query_point(Position::new(0.5, 0.0)), vec![Entity::from_raw(10)]);
+///
+/// let (nearest, distance) = quadtree.nearest(Position::new(3.0, 0.0)).unwrap();
+/// assert_eq!(nearest, Entity::from_raw(11));
+/// assert_eq!(distance, F32(1.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Quadtree<C: Coordinate> {
+    root: QuadtreeNode<C>,
+    bounds: HashMap<Entity, AxisAlignedBoundingBox<C>>,
+    layers: HashMap<Entity, CollisionLayers>,
+    max_depth: usize,
+    capacity: usize,
+}
+
+#[derive(Debug, Clone)]
+struct QuadtreeNode<C: Coordinate> {
+    bounds: AxisAlignedBoundingBox<C>,
+    entries: Vec<(Entity, AxisAlignedBoundingBox<C>)>,
+    children: Option<Box<[QuadtreeNode<C>; 4]>>,
+}
+
+impl<C: Coordinate> Quadtree<C> {
+    /// Creates an empty quadtree covering `bounds`
+    ///
+    /// Nodes are split once they hold more than `capacity` entities, until they are `max_depth` levels deep.
+    #[must_use]
+    pub fn new(bounds: AxisAlignedBoundingBox<C>, max_depth: usize, capacity: usize) -> Self {
+        Self {
+            root: QuadtreeNode::new(bounds),
+            bounds: HashMap::default(),
+            layers: HashMap::default(),
+            max_depth,
+            capacity,
+        }
+    }
+
+    /// The region covered by the root of this quadtree
+    #[inline]
+    #[must_use]
+    pub fn root_bounds(&self) -> &AxisAlignedBoundingBox<C> {
+        &self.root.bounds
+    }
+
+    /// The maximum number of levels below the root
+    #[inline]
+    #[must_use]
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    /// The number of entities a node can hold before it is split
+    #[inline]
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// The number of entities stored
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.bounds.len()
+    }
+
+    /// Are there no entities stored?
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.bounds.is_empty()
+    }
+
+    /// The bounding box that `entity` was stored with, if any
+    #[inline]
+    #[must_use]
+    pub fn get(&self, entity: Entity) -> Option<&AxisAlignedBoundingBox<C>> {
+        self.bounds.get(&entity)
+    }
+
+    /// The [`CollisionLayers`] of `entity`, which default to [`CollisionLayers::ALL`]
+    #[inline]
+    #[must_use]
+    pub fn layers(&self, entity: Entity) -> CollisionLayers {
+        self.layers.get(&entity).copied().unwrap_or_default()
+    }
+
+    /// Sets the [`CollisionLayers`] of `entity`, which are used to filter queries
+    ///
+    /// Returns `false` if the entity is not stored.
+    pub fn set_layers(&mut self, entity: Entity, layers: CollisionLayers) -> bool {
+        if self.bounds.contains_key(&entity) {
+            self.layers.insert(entity, layers);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Stores `entity` with the bounding box `aabb`
+    ///
+    /// If the entity was already stored, this is equivalent to [`Quadtree::update`].
+    pub fn insert(&mut self, entity: Entity, aabb: AxisAlignedBoundingBox<C>) {
+        if let Some(old_aabb) = self.bounds.remove(&entity) {
+            self.root.remove(entity, &old_aabb, self.capacity);
+        }
+
+        self.root
+            .insert(entity, aabb.clone(), 0, self.max_depth, self.capacity);
+        self.bounds.insert(entity, aabb);
+    }
+
+    /// Moves `entity` to its new bounding box, `aabb`
+    ///
+    /// Does nothing if the bounding box is unchanged.
+    pub fn update(&mut self, entity: Entity, aabb: AxisAlignedBoundingBox<C>) {
+        if self.get(entity) != Some(&aabb) {
+            self.insert(entity, aabb);
+        }
+    }
+
+    /// Removes `entity`, returning the bounding box that it was stored with
+    pub fn remove(&mut self, entity: Entity) -> Option<AxisAlignedBoundingBox<C>> {
+        self.layers.remove(&entity);
+        let aabb = self.bounds.remove(&entity)?;
+        self.root.remove(entity, &aabb, self.capacity);
+        Some(aabb)
+    }
+
+    /// Removes all entities, keeping the configuration of the quadtree
+    pub fn clear(&mut self) {
+        self.root = QuadtreeNode::new(self.root.bounds.clone());
+        self.bounds.clear();
+        self.layers.clear();
+    }
+
+    /// All entities whose bounding box overlaps `region`, sorted
+    ///
+    /// Entities are found regardless of their [`CollisionLayers`].
+    #[must_use]
+    pub fn query_region(&self, region: &AxisAlignedBoundingBox<C>) -> Vec<Entity> {
+        let mut found = Vec::new();
+        self.root.query_region(region, &|_| true, &mut found);
+
+        found.sort_unstable();
+        found
+    }
+
+    /// All entities that interact with `layers` whose bounding box overlaps `region`, sorted
+    #[must_use]
+    pub fn query_region_with_layers(
+        &self,
+        region: &AxisAlignedBoundingBox<C>,
+        layers: &CollisionLayers,
+    ) -> Vec<Entity> {
+        let mut found = Vec::new();
+        self.root
+            .query_region(region, &|entity| self.interacts(entity, layers), &mut found);
+
+        found.sort_unstable();
+        found
+    }
+
+    /// All entities whose bounding box contains `position`, sorted
+    ///
+    /// Entities are found regardless of their [`CollisionLayers`].
+    #[must_use]
+    pub fn query_point(&self, position: Position<C>) -> Vec<Entity> {
+        self.query_region(&AxisAlignedBoundingBox::from(position))
+    }
+
+    /// All entities that interact with `layers` whose bounding box contains `position`, sorted
+    #[must_use]
+    pub fn query_point_with_layers(
+        &self,
+        position: Position<C>,
+        layers: &CollisionLayers,
+    ) -> Vec<Entity> {
+        self.query_region_with_layers(&AxisAlignedBoundingBox::from(position), layers)
+    }
+
+    /// The entity whose bounding box is closest to `position`, and the distance to it
+    ///
+    /// Entities whose bounding box contains `position` have a distance of zero,
+    /// and entities are found regardless of their [`CollisionLayers`].
+    /// Returns `None` if the quadtree is empty.
+    #[must_use]
+    pub fn nearest(&self, position: Position<C>) -> Option<(Entity, C)> {
+        self.nearest_matching(position, &|_| true)
+    }
+
+    /// The entity that interacts with `layers` whose bounding box is closest to `position`, and the distance to it
+    ///
+    /// Entities whose bounding box contains `position` have a distance of zero.
+    /// Returns `None` if no such entity exists.
+    #[must_use]
+    pub fn nearest_with_layers(
+        &self,
+        position: Position<C>,
+        layers: &CollisionLayers,
+    ) -> Option<(Entity, C)> {
+        self.nearest_matching(position, &|entity| self.interacts(entity, layers))
+    }
+
+    /// The entity that passes `filter` whose bounding box is closest to `position`, and the distance to it
+    fn nearest_matching(
+        &self,
+        position: Position<C>,
+        filter: &impl Fn(Entity) -> bool,
+    ) -> Option<(Entity, C)> {
+        let point = Vec2::from(position);
+        let mut best = None;
+        self.root.nearest(point, filter, &mut best);
+
+        best.map(|(entity, distance_squared): (Entity, f32)| {
+            (entity, C::from(distance_squared.sqrt()))
+        })
+    }
+
+    /// Can `entity` interact with `layers`?
+    #[inline]
+    fn interacts(&self, entity: Entity, layers: &CollisionLayers) -> bool {
+        self.layers(entity).interacts_with(layers)
+    }
+}
+
+impl<C: Coordinate> QuadtreeNode<C> {
+    fn new(bounds: AxisAlignedBoundingBox<C>) -> Self {
+        Self {
+            bounds,
+            entries: Vec::new(),
+            children: None,
+        }
+    }
+
+    fn insert(
+        &mut self,
+        entity: Entity,
+        aabb: AxisAlignedBoundingBox<C>,
+        depth: usize,
+        max_depth: usize,
+        capacity: usize,
+    ) {
+        if let Some(child) = self.child_containing(&aabb) {
+            child.insert(entity, aabb, depth + 1, max_depth, capacity);
+            return;
+        }
+
+        self.entries.push((entity, aabb));
+
+        if self.children.is_none() && self.entries.len() > capacity && depth < max_depth {
+            self.split(depth, max_depth, capacity);
+        }
+    }
+
+    /// Subdivides this leaf, pushing down every entry that fits entirely within a child
+    fn split(&mut self, depth: usize, max_depth: usize, capacity: usize) {
+        let [first, second, third, fourth] = self.bounds.quadrants();
+        self.children = Some(Box::new([
+            Self::new(first),
+            Self::new(second),
+            Self::new(third),
+            Self::new(fourth),
+        ]));
+
+        for (entity, aabb) in std::mem::take(&mut self.entries) {
+            match self.child_containing(&aabb) {
+                Some(child) => child.insert(entity, aabb, depth + 1, max_depth, capacity),
+                None => self.entries.push((entity, aabb)),
+            }
+        }
+    }
+
+    fn remove(&mut self, entity: Entity, aabb: &AxisAlignedBoundingBox<C>, capacity: usize) {
+        match self.child_containing(aabb) {
+            Some(child) => child.remove(entity, aabb, capacity),
+            None => self.entries.retain(|&(stored, _)| stored != entity),
+        }
+
+        self.try_merge(capacity);
+    }
+
+    /// Collapses this node's children back into it once they are sparse enough
+    fn try_merge(&mut self, capacity: usize) {
+        let children = match &mut self.children {
+            Some(children) => children,
+            None => return,
+        };
+
+        if children.iter().any(|child| child.children.is_some()) {
+            return;
+        }
+
+        let total: usize = self.entries.len()
+            + children
+                .iter()
+                .map(|child| child.entries.len())
+                .sum::<usize>();
+
+        if total <= capacity {
+            for child in children.iter_mut() {
+                self.entries.append(&mut child.entries);
+            }
+            self.children = None;
+        }
+    }
+
+    /// The child whose bounds entirely contain `aabb`, if any
+    fn child_containing(&mut self, aabb: &AxisAlignedBoundingBox<C>) -> Option<&mut Self> {
+        self.children
+            .as_mut()?
+            .iter_mut()
+            .find(|child| contains_box(&child.bounds, aabb))
+    }
+
+    fn query_region(
+        &self,
+        region: &AxisAlignedBoundingBox<C>,
+        filter: &impl Fn(Entity) -> bool,
+        found: &mut Vec<Entity>,
+    ) {
+        found.extend(
+            self.entries
+                .iter()
+                .filter(|&&(entity, ref aabb)| {
+                    filter(entity) && aabb.intersection(region).is_some()
+                })
+                .map(|&(entity, _)| entity),
+        );
+
+        if let Some(children) = &self.children {
+            for child in children.iter() {
+                if child.bounds.intersection(region).is_some() {
+                    child.query_region(region, filter, found);
+                }
+            }
+        }
+    }
+
+    fn nearest(
+        &self,
+        point: Vec2,
+        filter: &impl Fn(Entity) -> bool,
+        best: &mut Option<(Entity, f32)>,
+    ) {
+        for (entity, aabb) in self.entries.iter().filter(|(entity, _)| filter(*entity)) {
+            let distance_squared = distance_squared_to(aabb, point);
+
+            if best
+                .filter(|&(_, best_distance)| best_distance <= distance_squared)
+                .is_none()
+            {
+                *best = Some((*entity, distance_squared));
+            }
+        }
+
+        if let Some(children) = &self.children {
+            let mut children: Vec<(f32, &Self)> = children
+                .iter()
+                .map(|child| (distance_squared_to(&child.bounds, point), child))
+                .collect();
+            // Searching the closest children first lets us skip the rest more often
+            children.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+            for (distance_squared, child) in children {
+                if best
+                    .filter(|&(_, best_distance)| best_distance <= distance_squared)
+                    .is_none()
+                {
+                    child.nearest(point, filter, best);
+                }
+            }
+        }
+    }
+}
+
+/// Does `outer` entirely contain `inner`?
+#[inline]
+fn contains_box<C: Coordinate>(
+    outer: &AxisAlignedBoundingBox<C>,
+    inner: &AxisAlignedBoundingBox<C>,
+) -> bool {
+    (outer.left <= inner.left)
+        & (inner.right <= outer.right)
+        & (outer.bottom <= inner.bottom)
+        & (inner.top <= outer.top)
+}
+
+/// The squared distance from `point` to the closest point within `aabb`
+#[inline]
+pub(crate) fn distance_squared_to<C: Coordinate>(
+    aabb: &AxisAlignedBoundingBox<C>,
+    point: Vec2,
+) -> f32 {
+    let low = Vec2::new(aabb.left.into(), aabb.bottom.into());
+    let high = Vec2::new(aabb.right.into(), aabb.top.into());
+
+    point.clamp(low, high).distance_squared(point)
+}
@@ -0,0 +1,405 @@
+use super::polygon::outward_normal;
+use super::{
+    AxisAlignedBoundingBox, BoundingCircle, BoundingPolygon, BoundingRegion, Collider,
+    CollisionLayers,
+};
+use crate::coordinate::Coordinate;
+use crate::orientation::Direction;
+use crate::position::Position;
+use bevy_ecs::prelude::*;
+use bevy_ecs::system::SystemParam;
+use bevy_math::Vec2;
+
+/// A half-line that starts at `origin` and extends forever in `direction`
+///
+/// Used for hitscan weapons, line-of-sight checks and so on.
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::bounding::{AxisAlignedBoundingBox, Ray, Raycast};
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::orientation::Direction;
+/// use leafwing_2d::position::Position;
+///
+/// let wall = AxisAlignedBoundingBox::<F32>::new(2.0, 3.0, -5.0, 5.0);
+/// let ray = Ray::new(Position::new(0.0, 0.0), Direction::EAST);
+///
+/// let hit = wall.cast_ray(ray).expect("The ray points straight at the wall.");
+/// assert_eq!(hit.distance, F32(2.0));
+/// assert_eq!(hit.point, Position::new(2.0, 0.0));
+/// assert_eq!(hit.normal, Direction::WEST);
+///
+/// // Rays only travel forwards
+/// assert!(wall.cast_ray(Ray::new(Position::new(0.0, 0.0), Direction::WEST)).is_none());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Ray<C: Coordinate> {
+    /// The [`Position`] that the ray starts from
+    pub origin: Position<C>,
+    /// The [`Direction`] that the ray travels in
+    pub direction: Direction,
+}
+
+impl<C: Coordinate> Ray<C> {
+    /// Creates a new [`Ray`] starting at `origin` that travels in `direction`
+    #[inline]
+    #[must_use]
+    pub fn new(origin: Position<C>, direction: impl Into<Direction>) -> Self {
+        Self {
+            origin,
+            direction: direction.into(),
+        }
+    }
+
+    /// The [`Position`] that is `distance` along this ray
+    #[inline]
+    #[must_use]
+    pub fn point_at(&self, distance: C) -> Position<C> {
+        let distance: f32 = distance.into();
+
+        (self.origin_vec2() + self.direction.unit_vector() * distance).into()
+    }
+
+    #[inline]
+    pub(crate) fn origin_vec2(&self) -> Vec2 {
+        self.origin.into()
+    }
+}
+
+/// Where a [`Ray`] struck a [`BoundingRegion`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RayHit<C: Coordinate> {
+    /// How far the ray travelled before striking the region
+    pub distance: C,
+    /// The [`Position`] at which the ray struck the region
+    pub point: Position<C>,
+    /// The outward-facing [`Direction`] of the surface that was struck
+    pub normal: Direction,
+}
+
+impl<C: Coordinate> RayHit<C> {
+    /// Constructs a hit `distance` along the `ray`
+    fn new(ray: Ray<C>, distance: f32, normal: Vec2) -> Self {
+        RayHit {
+            distance: C::from(distance),
+            point: (ray.origin_vec2() + ray.direction.unit_vector() * distance).into(),
+            normal: normal.try_into().unwrap_or(-ray.direction),
+        }
+    }
+
+    /// Constructs the hit used when the ray starts inside of the region
+    fn inside(ray: Ray<C>) -> Self {
+        RayHit {
+            distance: C::ZERO,
+            point: ray.origin,
+            normal: -ray.direction,
+        }
+    }
+}
+
+/// A [`BoundingRegion`] that can be struck by a [`Ray`]
+pub trait Raycast: BoundingRegion {
+    /// Casts the `ray` against this region, returning the first point that it strikes
+    ///
+    /// If the ray starts inside of this region,
+    /// a hit with a distance of zero at the ray's origin is returned,
+    /// whose normal points back against the ray.
+    ///
+    /// Returns `None` if the ray misses.
+    #[must_use]
+    fn cast_ray(&self, ray: Ray<Self::C>) -> Option<RayHit<Self::C>>;
+}
+
+impl<C: Coordinate> Raycast for AxisAlignedBoundingBox<C> {
+    fn cast_ray(&self, ray: Ray<C>) -> Option<RayHit<C>> {
+        cast_ray_through_box(ray, self.bottom_left().into(), self.top_right().into())
+    }
+}
+
+/// Casts the `ray` against the axis-aligned box spanning from `low` to `high`, using the slab method
+pub(crate) fn cast_ray_through_box<C: Coordinate>(
+    ray: Ray<C>,
+    low: Vec2,
+    high: Vec2,
+) -> Option<RayHit<C>> {
+    let origin = ray.origin_vec2();
+
+    if origin.cmpge(low).all() && origin.cmple(high).all() {
+        return Some(RayHit::inside(ray));
+    }
+
+    let direction = ray.direction.unit_vector();
+
+    // Find where the ray enters and exits each pair of parallel sides
+    let mut entry = f32::NEG_INFINITY;
+    let mut exit = f32::INFINITY;
+    let mut normal = Vec2::ZERO;
+
+    for (axis, unit) in [(0, Vec2::X), (1, Vec2::Y)] {
+        if direction[axis] == 0. {
+            // Parallel to this slab, so we must already be within it
+            if origin[axis] < low[axis] || origin[axis] > high[axis] {
+                return None;
+            }
+            continue;
+        }
+
+        let near = (low[axis] - origin[axis]) / direction[axis];
+        let far = (high[axis] - origin[axis]) / direction[axis];
+        let (near, far, near_normal) = if near <= far {
+            (near, far, -unit)
+        } else {
+            (far, near, unit)
+        };
+
+        if near > entry {
+            entry = near;
+            normal = near_normal;
+        }
+        exit = exit.min(far);
+    }
+
+    if entry > exit || entry < 0. {
+        None
+    } else {
+        Some(RayHit::new(ray, entry, normal))
+    }
+}
+
+impl<C: Coordinate> Raycast for BoundingCircle<C> {
+    fn cast_ray(&self, ray: Ray<C>) -> Option<RayHit<C>> {
+        if self.contains(ray.origin) {
+            return Some(RayHit::inside(ray));
+        }
+
+        let center = self.center_vec2();
+        let radius: f32 = self.radius.into();
+        let offset = ray.origin_vec2() - center;
+        let direction = ray.direction.unit_vector();
+
+        // Solve |offset + t * direction| = radius for t; direction has unit length
+        let half_b = offset.dot(direction);
+        let c = offset.length_squared() - radius * radius;
+        let discriminant = half_b * half_b - c;
+
+        if discriminant < 0. {
+            return None;
+        }
+
+        let distance = -half_b - discriminant.sqrt();
+        if distance < 0. {
+            return None;
+        }
+
+        let point = ray.origin_vec2() + direction * distance;
+        Some(RayHit::new(ray, distance, point - center))
+    }
+}
+
+impl<C: Coordinate> Raycast for BoundingPolygon<C> {
+    fn cast_ray(&self, ray: Ray<C>) -> Option<RayHit<C>> {
+        if self.contains(ray.origin) {
+            return Some(RayHit::inside(ray));
+        }
+
+        let origin = ray.origin_vec2();
+        let direction = ray.direction.unit_vector();
+
+        self.edges()
+            .filter(|(start, end)| start != end)
+            .filter_map(|(start, end)| {
+                let edge = end - start;
+                let denominator = direction.perp_dot(edge);
+
+                // Parallel edges can only be grazed, which never counts as entering the polygon
+                if denominator == 0. {
+                    return None;
+                }
+
+                let distance = (start - origin).perp_dot(edge) / denominator;
+                let along_edge = (start - origin).perp_dot(direction) / denominator;
+
+                if distance >= 0. && (0. ..=1.).contains(&along_edge) {
+                    // Degenerate polygons have edges in both directions, so orient the normal against the ray
+                    let normal = outward_normal(start, end);
+                    if normal.dot(direction) > 0. {
+                        Some((distance, -normal))
+                    } else {
+                        Some((distance, normal))
+                    }
+                } else {
+                    None
+                }
+            })
+            .min_by(|(a, _), (b, _)| a.total_cmp(b))
+            .map(|(distance, normal)| RayHit::new(ray, distance, normal))
+    }
+}
+
+/// Finds the [`Entity`] whose region is struck first by the `ray`
+///
+/// Entities are supplied as pairs of `(entity, region)`,
+/// which can be created by iterating over a [`Query`].
+#[must_use]
+pub fn nearest_ray_hit<'a, R: Raycast + 'a>(
+    ray: Ray<R::C>,
+    regions: impl IntoIterator<Item = (Entity, &'a R)>,
+) -> Option<(Entity, RayHit<R::C>)> {
+    regions
+        .into_iter()
+        .filter_map(|(entity, region)| region.cast_ray(ray).map(|hit| (entity, hit)))
+        .min_by(|(_, a), (_, b)| {
+            a.distance
+                .partial_cmp(&b.distance)
+                .unwrap_or(core::cmp::Ordering::Equal)
+        })
+}
+
+/// A [`SystemParam`] for casting [`Rays`](Ray) against every entity with a bounding component
+///
+/// [`AxisAlignedBoundingBox`], [`BoundingCircle`], [`BoundingPolygon`] and [`Collider`] components are all checked.
+/// The `_with_layers` methods only consider entities whose [`CollisionLayers`] interact with the provided layers.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_2d::bounding::{Ray, RaycastQuery};
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::orientation::Direction;
+/// use leafwing_2d::position::Position;
+///
+/// #[derive(Component)]
+/// struct Turret;
+///
+/// fn fire_hitscan(turrets: Query<(&Position<F32>, &Direction), With<Turret>>, raycast: RaycastQuery<F32>) {
+///     for (&position, &direction) in turrets.iter() {
+///         if let Some((entity, hit)) = raycast.cast_ray(Ray::new(position, direction)) {
+///             println!("Hit {entity:?} at {:?}", hit.point);
+///         }
+///     }
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct RaycastQuery<'w, 's, C: Coordinate> {
+    aabbs: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static AxisAlignedBoundingBox<C>,
+            Option<&'static CollisionLayers>,
+        ),
+    >,
+    circles: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static BoundingCircle<C>,
+            Option<&'static CollisionLayers>,
+        ),
+    >,
+    polygons: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static BoundingPolygon<C>,
+            Option<&'static CollisionLayers>,
+        ),
+    >,
+    colliders: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static Collider<C>,
+            Option<&'static CollisionLayers>,
+        ),
+    >,
+}
+
+impl<'w, 's, C: Coordinate> RaycastQuery<'w, 's, C> {
+    /// Casts the `ray` against all bounding components, returning the nearest hit
+    ///
+    /// Entities whose bounding region contains the origin of the ray are hit at a distance of zero.
+    #[must_use]
+    pub fn cast_ray(&self, ray: Ray<C>) -> Option<(Entity, RayHit<C>)> {
+        self.cast_ray_with_layers(ray, &CollisionLayers::ALL)
+    }
+
+    /// Casts the `ray` against the bounding components of entities that interact with `layers`, returning the nearest hit
+    ///
+    /// Entities whose bounding region contains the origin of the ray are hit at a distance of zero.
+    #[must_use]
+    pub fn cast_ray_with_layers(
+        &self,
+        ray: Ray<C>,
+        layers: &CollisionLayers,
+    ) -> Option<(Entity, RayHit<C>)> {
+        [
+            nearest_ray_hit(ray, filter_layers(self.aabbs.iter(), layers)),
+            nearest_ray_hit(ray, filter_layers(self.circles.iter(), layers)),
+            nearest_ray_hit(ray, filter_layers(self.polygons.iter(), layers)),
+            nearest_ray_hit(ray, filter_layers(self.colliders.iter(), layers)),
+        ]
+        .into_iter()
+        .flatten()
+        .min_by(|(_, a), (_, b)| {
+            a.distance
+                .partial_cmp(&b.distance)
+                .unwrap_or(core::cmp::Ordering::Equal)
+        })
+    }
+
+    /// Casts the `ray` against all bounding components, returning every hit sorted by distance
+    #[must_use]
+    pub fn cast_ray_all(&self, ray: Ray<C>) -> Vec<(Entity, RayHit<C>)> {
+        self.cast_ray_all_with_layers(ray, &CollisionLayers::ALL)
+    }
+
+    /// Casts the `ray` against the bounding components of entities that interact with `layers`,
+    /// returning every hit sorted by distance
+    #[must_use]
+    pub fn cast_ray_all_with_layers(
+        &self,
+        ray: Ray<C>,
+        layers: &CollisionLayers,
+    ) -> Vec<(Entity, RayHit<C>)> {
+        let aabb_hits = filter_layers(self.aabbs.iter(), layers)
+            .filter_map(|(e, r)| r.cast_ray(ray).map(|h| (e, h)));
+        let circle_hits = filter_layers(self.circles.iter(), layers)
+            .filter_map(|(e, r)| r.cast_ray(ray).map(|h| (e, h)));
+        let polygon_hits = filter_layers(self.polygons.iter(), layers)
+            .filter_map(|(e, r)| r.cast_ray(ray).map(|h| (e, h)));
+        let collider_hits = filter_layers(self.colliders.iter(), layers)
+            .filter_map(|(e, r)| r.cast_ray(ray).map(|h| (e, h)));
+
+        let mut hits: Vec<_> = aabb_hits
+            .chain(circle_hits)
+            .chain(polygon_hits)
+            .chain(collider_hits)
+            .collect();
+        hits.sort_by(|(_, a), (_, b)| {
+            a.distance
+                .partial_cmp(&b.distance)
+                .unwrap_or(core::cmp::Ordering::Equal)
+        });
+        hits
+    }
+}
+
+/// Keeps only the regions whose [`CollisionLayers`] interact with `layers`
+fn filter_layers<'a, R: 'a>(
+    regions: impl Iterator<Item = (Entity, &'a R, Option<&'a CollisionLayers>)>,
+    layers: &'a CollisionLayers,
+) -> impl Iterator<Item = (Entity, &'a R)> {
+    regions
+        .filter(move |(_, _, maybe_layers)| {
+            maybe_layers
+                .copied()
+                .unwrap_or_default()
+                .interacts_with(layers)
+        })
+        .map(|(entity, region, _)| (entity, region))
+}
@@ -0,0 +1,99 @@
+use super::AxisAlignedBoundingBox;
+use crate::coordinate::Coordinate;
+use bevy_ecs::prelude::Component;
+use bevy_math::Vec2;
+
+/// Marks an entity's [`AxisAlignedBoundingBox`] as an immovable obstacle for entities with [`CollisionResponse`]
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StaticCollider;
+
+/// Pushes an entity out of any [`StaticCollider`] that it overlaps after kinematics have been applied
+///
+/// The entity is moved the shortest distance needed to separate its [`AxisAlignedBoundingBox`] from the obstacle,
+/// and the part of its [`Velocity`](crate::kinematics::Velocity) heading into the obstacle is removed,
+/// letting it slide along walls.
+/// With a non-zero [`restitution`](CollisionResponse::restitution), that part of its velocity is reflected instead, bouncing it off the obstacle.
+///
+/// Only obstacles whose [`CollisionLayers`](super::CollisionLayers) interact with the entity's own are considered.
+/// Requires [`TwoDPlugin`](crate::plugin::TwoDPlugin) with `kinematics` enabled.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+pub struct CollisionResponse {
+    restitution: f32,
+}
+
+impl CollisionResponse {
+    /// Slides along obstacles without bouncing
+    pub const SLIDE: CollisionResponse = CollisionResponse { restitution: 0.0 };
+
+    /// Creates a new collision response that bounces off obstacles with the provided `restitution`
+    ///
+    /// # Panics
+    /// `restitution` must be between 0 and 1.
+    #[inline]
+    #[must_use]
+    pub fn new(restitution: f32) -> Self {
+        assert!((0.0..=1.0).contains(&restitution));
+
+        Self { restitution }
+    }
+
+    /// The fraction of the entity's speed into an obstacle that is kept as it bounces away
+    ///
+    /// `0.0` slides along the obstacle, while `1.0` is a perfectly elastic bounce.
+    #[inline]
+    #[must_use]
+    pub fn restitution(&self) -> f32 {
+        self.restitution
+    }
+
+    /// Removes or reflects the part of `velocity` that points against the contact `normal`
+    ///
+    /// `normal` should be a unit vector pointing away from the obstacle.
+    #[must_use]
+    pub fn respond(&self, velocity: Vec2, normal: Vec2) -> Vec2 {
+        let normal_speed = velocity.dot(normal);
+
+        if normal_speed < 0.0 {
+            velocity - (1.0 + self.restitution) * normal_speed * normal
+        } else {
+            velocity
+        }
+    }
+}
+
+/// The shortest translation that moves `mover` out of `obstacle`, if they overlap
+///
+/// Regions that are merely touching are not considered to overlap.
+pub(crate) fn separation<C: Coordinate>(
+    mover: &AxisAlignedBoundingBox<C>,
+    obstacle: &AxisAlignedBoundingBox<C>,
+) -> Option<Vec2> {
+    let mover_low = Vec2::new(mover.left.into(), mover.bottom.into());
+    let mover_high = Vec2::new(mover.right.into(), mover.top.into());
+    let obstacle_low = Vec2::new(obstacle.left.into(), obstacle.bottom.into());
+    let obstacle_high = Vec2::new(obstacle.right.into(), obstacle.top.into());
+
+    // How far the mover would need to travel in the negative and positive direction along each axis
+    let negative = mover_high - obstacle_low;
+    let positive = obstacle_high - mover_low;
+    if negative.min(positive).min_element() <= 0.0 {
+        return None;
+    }
+
+    let x = if negative.x < positive.x {
+        -negative.x
+    } else {
+        positive.x
+    };
+    let y = if negative.y < positive.y {
+        -negative.y
+    } else {
+        positive.y
+    };
+
+    if x.abs() <= y.abs() {
+        Some(Vec2::new(x, 0.0))
+    } else {
+        Some(Vec2::new(0.0, y))
+    }
+}
@@ -0,0 +1,302 @@
+use super::{AxisAlignedBoundingBox, CollisionLayers};
+use crate::coordinate::Coordinate;
+use crate::position::Position;
+use bevy_ecs::entity::Entity;
+use bevy_math::Vec2;
+use std::collections::{HashMap, HashSet};
+
+/// A uniform grid that buckets entities by their [`AxisAlignedBoundingBox`], for fast broadphase collision checks
+///
+/// Each entity is stored in every cell that its bounding box touches,
+/// so only entities that share a cell need to be checked against each other.
+/// Entities whose bounding box is not finite or would touch more than [`SpatialHash::MAX_CELLS`] cells
+/// are stored in a separate overflow list instead, and are checked against every other entity.
+///
+/// When inserted as a resource, [`TwoDPlugin`](crate::plugin::TwoDPlugin) keeps this up to date
+/// with the [`AxisAlignedBoundingBox`], [`BoundingCircle`](super::BoundingCircle), [`BoundingPolygon`](super::BoundingPolygon)
+/// and [`CollisionLayers`] components of each entity.
+/// Choose a `cell_size` that is a little larger than your typical entity.
+///
+/// # Example
+/// ```rust
+/// use bevy::ecs::entity::Entity;
+/// use leafwing_2d::bounding::{AxisAlignedBoundingBox, SpatialHash};
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::position::Position;
+///
+/// let mut spatial_hash = SpatialHash::<F32>::new(10.0);
+/// let player = Entity::from_raw(0);
+/// let enemy = Entity::from_raw(1);
+///
+/// spatial_hash.insert(player, AxisAlignedBoundingBox::from_size(Position::new(0.0, 0.0), 1.0, 1.0));
+/// spatial_hash.insert(enemy, AxisAlignedBoundingBox::from_size(Position::new(1.5, 0.0), 1.0, 1.0));
+///
+/// assert_eq!(spatial_hash.potential_pairs(), vec![(player, enemy)]);
+/// assert_eq!(spatial_hash.query_point(Position::new(-0.5, 0.0)), vec![player]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SpatialHash<C: Coordinate> {
+    cell_size: f32,
+    cells: HashMap<(i32, i32), Vec<Entity>>,
+    oversized: Vec<Entity>,
+    bounds: HashMap<Entity, AxisAlignedBoundingBox<C>>,
+    layers: HashMap<Entity, CollisionLayers>,
+}
+
+impl<C: Coordinate> SpatialHash<C> {
+    /// The largest number of cells that a single bounding box is stored in
+    ///
+    /// Larger bounding boxes are kept in an overflow list, and queries over larger regions check every entity.
+    pub const MAX_CELLS: i64 = 4096;
+
+    /// Creates an empty spatial hash, whose square cells have sides of length `cell_size`
+    ///
+    /// # Panics
+    /// `cell_size` must be greater than zero.
+    #[must_use]
+    pub fn new(cell_size: f32) -> Self {
+        assert!(cell_size > 0.);
+
+        Self {
+            cell_size,
+            cells: HashMap::default(),
+            oversized: Vec::default(),
+            bounds: HashMap::default(),
+            layers: HashMap::default(),
+        }
+    }
+
+    /// The length of the sides of each cell
+    #[inline]
+    #[must_use]
+    pub fn cell_size(&self) -> f32 {
+        self.cell_size
+    }
+
+    /// The number of entities stored
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.bounds.len()
+    }
+
+    /// Are there no entities stored?
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.bounds.is_empty()
+    }
+
+    /// The bounding box that `entity` was stored with, if any
+    #[inline]
+    #[must_use]
+    pub fn get(&self, entity: Entity) -> Option<&AxisAlignedBoundingBox<C>> {
+        self.bounds.get(&entity)
+    }
+
+    /// The [`CollisionLayers`] of `entity`, which default to [`CollisionLayers::ALL`]
+    #[inline]
+    #[must_use]
+    pub fn layers(&self, entity: Entity) -> CollisionLayers {
+        self.layers.get(&entity).copied().unwrap_or_default()
+    }
+
+    /// Sets the [`CollisionLayers`] of `entity`, which are used to filter pairs and queries
+    ///
+    /// Returns `false` if the entity is not stored.
+    pub fn set_layers(&mut self, entity: Entity, layers: CollisionLayers) -> bool {
+        if self.bounds.contains_key(&entity) {
+            self.layers.insert(entity, layers);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Stores `entity` in every cell that `aabb` touches
+    ///
+    /// If the entity was already stored, its previous bounds are replaced, but its [`CollisionLayers`] are kept.
+    pub fn insert(&mut self, entity: Entity, aabb: AxisAlignedBoundingBox<C>) {
+        self.remove_from_cells(entity);
+
+        match self.cell_range(&aabb) {
+            Some(range) => {
+                for cell in cells_in(range) {
+                    self.cells.entry(cell).or_default().push(entity);
+                }
+            }
+            None => self.oversized.push(entity),
+        }
+
+        self.bounds.insert(entity, aabb);
+    }
+
+    /// Removes `entity`, returning the bounding box that it was stored with
+    pub fn remove(&mut self, entity: Entity) -> Option<AxisAlignedBoundingBox<C>> {
+        self.layers.remove(&entity);
+        self.remove_from_cells(entity)
+    }
+
+    fn remove_from_cells(&mut self, entity: Entity) -> Option<AxisAlignedBoundingBox<C>> {
+        let aabb = self.bounds.remove(&entity)?;
+
+        match self.cell_range(&aabb) {
+            Some(range) => {
+                for cell in cells_in(range) {
+                    if let Some(entities) = self.cells.get_mut(&cell) {
+                        entities.retain(|&stored| stored != entity);
+
+                        if entities.is_empty() {
+                            self.cells.remove(&cell);
+                        }
+                    }
+                }
+            }
+            None => self.oversized.retain(|&stored| stored != entity),
+        }
+
+        Some(aabb)
+    }
+
+    /// Removes all entities
+    pub fn clear(&mut self) {
+        self.cells.clear();
+        self.oversized.clear();
+        self.bounds.clear();
+        self.layers.clear();
+    }
+
+    /// All entities whose bounding box overlaps `region`, sorted and without duplicates
+    ///
+    /// Entities are found regardless of their [`CollisionLayers`].
+    #[must_use]
+    pub fn query_region(&self, region: &AxisAlignedBoundingBox<C>) -> Vec<Entity> {
+        self.query_region_matching(region, |_| true)
+    }
+
+    /// All entities that interact with `layers` whose bounding box overlaps `region`, sorted and without duplicates
+    #[must_use]
+    pub fn query_region_with_layers(
+        &self,
+        region: &AxisAlignedBoundingBox<C>,
+        layers: &CollisionLayers,
+    ) -> Vec<Entity> {
+        self.query_region_matching(region, |entity| self.layers(entity).interacts_with(layers))
+    }
+
+    /// All entities that pass `filter` whose bounding box overlaps `region`, sorted and without duplicates
+    fn query_region_matching(
+        &self,
+        region: &AxisAlignedBoundingBox<C>,
+        filter: impl Fn(Entity) -> bool,
+    ) -> Vec<Entity> {
+        let candidates: Vec<Entity> = match self.cell_range(region) {
+            Some(range) => cells_in(range)
+                .filter_map(|cell| self.cells.get(&cell))
+                .flatten()
+                .chain(&self.oversized)
+                .copied()
+                .collect(),
+            None => self.bounds.keys().copied().collect(),
+        };
+
+        let mut found: Vec<Entity> = candidates
+            .into_iter()
+            .filter(|&entity| filter(entity) && self.bounds[&entity].intersection(region).is_some())
+            .collect();
+
+        found.sort_unstable();
+        found.dedup();
+        found
+    }
+
+    /// All entities whose bounding box contains `position`, sorted
+    ///
+    /// Entities are found regardless of their [`CollisionLayers`].
+    #[must_use]
+    pub fn query_point(&self, position: Position<C>) -> Vec<Entity> {
+        self.query_region(&AxisAlignedBoundingBox::from(position))
+    }
+
+    /// All entities that interact with `layers` whose bounding box contains `position`, sorted
+    #[must_use]
+    pub fn query_point_with_layers(
+        &self,
+        position: Position<C>,
+        layers: &CollisionLayers,
+    ) -> Vec<Entity> {
+        self.query_region_with_layers(&AxisAlignedBoundingBox::from(position), layers)
+    }
+
+    /// Every pair of entities whose bounding boxes overlap and whose [`CollisionLayers`] interact
+    ///
+    /// Each pair is reported once, with the smaller [`Entity`] first, and the pairs are sorted.
+    /// Use this to narrow down which entities need more precise collision checks.
+    #[must_use]
+    pub fn potential_pairs(&self) -> Vec<(Entity, Entity)> {
+        let mut pairs = HashSet::new();
+        let mut check_pair = |a: Entity, b: Entity| {
+            let pair = if a < b { (a, b) } else { (b, a) };
+
+            if a != b
+                && !pairs.contains(&pair)
+                && self.layers(a).interacts_with(&self.layers(b))
+                && self.bounds[&a].intersection(&self.bounds[&b]).is_some()
+            {
+                pairs.insert(pair);
+            }
+        };
+
+        for entities in self.cells.values() {
+            for (i, &a) in entities.iter().enumerate() {
+                for &b in &entities[i + 1..] {
+                    check_pair(a, b);
+                }
+            }
+        }
+
+        // Oversized entities are not stored in any cell, so they must be checked against everything
+        for &a in &self.oversized {
+            for &b in self.bounds.keys() {
+                check_pair(a, b);
+            }
+        }
+
+        let mut pairs: Vec<(Entity, Entity)> = pairs.into_iter().collect();
+        pairs.sort_unstable();
+        pairs
+    }
+
+    /// The coordinates of the lowest and highest cells touched by `aabb`
+    ///
+    /// Returns `None` if `aabb` is not finite, or would touch more than [`SpatialHash::MAX_CELLS`] cells.
+    fn cell_range(&self, aabb: &AxisAlignedBoundingBox<C>) -> Option<((i32, i32), (i32, i32))> {
+        let low = Vec2::new(aabb.left.into(), aabb.bottom.into());
+        let high = Vec2::new(aabb.right.into(), aabb.top.into());
+
+        if !low.is_finite() || !high.is_finite() {
+            return None;
+        }
+
+        let (min_x, min_y) = self.cell_of(low);
+        let (max_x, max_y) = self.cell_of(high);
+        let columns = i64::from(max_x) - i64::from(min_x) + 1;
+        let rows = i64::from(max_y) - i64::from(min_y) + 1;
+
+        (columns.saturating_mul(rows) <= Self::MAX_CELLS).then(|| ((min_x, min_y), (max_x, max_y)))
+    }
+
+    /// The coordinates of the cell containing `point`
+    #[inline]
+    fn cell_of(&self, point: Vec2) -> (i32, i32) {
+        let cell = (point / self.cell_size).floor();
+        (cell.x as i32, cell.y as i32)
+    }
+}
+
+/// The coordinates of every cell between the lowest and highest cells, inclusive
+fn cells_in(
+    ((min_x, min_y), (max_x, max_y)): ((i32, i32), (i32, i32)),
+) -> impl Iterator<Item = (i32, i32)> {
+    (min_x..=max_x).flat_map(move |x| (min_y..=max_y).map(move |y| (x, y)))
+}
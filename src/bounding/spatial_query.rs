@@ -0,0 +1,268 @@
+use super::{AxisAlignedBoundingBox, BoundingRegion, Quadtree, SpatialHash};
+use crate::coordinate::Coordinate;
+use crate::position::Position;
+use bevy_ecs::prelude::*;
+use bevy_ecs::query::{FilterFetch, WorldQuery};
+use bevy_ecs::system::SystemParam;
+use bevy_math::Vec2;
+
+/// A [`SystemParam`] for finding entities by their [`Position`]
+///
+/// Only entities that match the query filter `F` are returned.
+///
+/// If a [`Quadtree`] or [`SpatialHash`] resource exists, it is used to avoid checking every entity;
+/// the [`Quadtree`] is preferred if both are present.
+/// In that case, only entities stored in the index are found (regardless of their [`CollisionLayers`](super::CollisionLayers)),
+/// and each entity's [`Position`] is assumed to lie within its bounding box.
+/// Otherwise, every entity with a [`Position`] is checked.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_2d::bounding::SpatialQuery;
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::position::Position;
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// #[derive(Component)]
+/// struct Enemy;
+///
+/// fn alert_nearby_enemies(
+///     players: Query<&Position<F32>, With<Player>>,
+///     enemies: SpatialQuery<F32, With<Enemy>>,
+/// ) {
+///     for &position in players.iter() {
+///         for (enemy, distance) in enemies.entities_within(position, 200.0) {
+///             println!("{enemy:?} is {distance:?} units away");
+///         }
+///     }
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct SpatialQuery<'w, 's, C: Coordinate, F: WorldQuery + 'static = ()>
+where
+    F::Fetch: FilterFetch,
+{
+    positions: Query<'w, 's, (Entity, &'static Position<C>), F>,
+    quadtree: Option<Res<'w, Quadtree<C>>>,
+    spatial_hash: Option<Res<'w, SpatialHash<C>>>,
+}
+
+impl<'w, 's, C: Coordinate, F: WorldQuery + 'static> SpatialQuery<'w, 's, C, F>
+where
+    F::Fetch: FilterFetch,
+{
+    /// All entities whose [`Position`] is exactly `point`, sorted
+    ///
+    /// This is most useful with [`DiscreteCoordinates`](crate::discrete::DiscreteCoordinate).
+    #[must_use]
+    pub fn entities_at(&self, point: Position<C>) -> Vec<Entity> {
+        let mut found: Vec<Entity> = self
+            .candidates(&AxisAlignedBoundingBox::from(point))
+            .filter(|&(_, position)| position == point)
+            .map(|(entity, _)| entity)
+            .collect();
+        found.sort_unstable();
+        found
+    }
+
+    /// All entities whose [`Position`] is within `radius` of `center`, sorted by distance
+    #[must_use]
+    pub fn entities_within<T: Into<C>>(&self, center: Position<C>, radius: T) -> Vec<(Entity, C)> {
+        let radius: C = radius.into();
+        let radius: f32 = radius.into();
+        let center_vec: Vec2 = center.into();
+
+        let mut found: Vec<(Entity, f32)> = self
+            .candidates(&bounds_around(center_vec, radius))
+            .map(|(entity, position)| (entity, center_vec.distance(position.into())))
+            .filter(|&(_, distance)| distance <= radius)
+            .collect();
+        sort_by_distance(&mut found);
+
+        found
+            .into_iter()
+            .map(|(entity, distance)| (entity, C::from(distance)))
+            .collect()
+    }
+
+    /// The `k` entities whose [`Position`] is closest to `point`, sorted by distance
+    ///
+    /// Fewer than `k` entities are returned if there are not enough entities to find.
+    #[must_use]
+    pub fn k_nearest(&self, point: Position<C>, k: usize) -> Vec<(Entity, C)> {
+        if k == 0 {
+            return Vec::new();
+        }
+
+        let point_vec: Vec2 = point.into();
+        let distances = |candidates: Vec<(Entity, Position<C>)>| -> Vec<(Entity, f32)> {
+            let mut found: Vec<_> = candidates
+                .into_iter()
+                .map(|(entity, position)| (entity, point_vec.distance(position.into())))
+                .collect();
+            sort_by_distance(&mut found);
+            found
+        };
+
+        let mut found = match self.index_extent() {
+            // Search outwards until we have found enough entities,
+            // or the search region covers every entity in the index
+            Some((mut half_extent, indexed)) => loop {
+                if !half_extent.is_finite() || half_extent > self.max_search_extent(indexed) {
+                    break distances(
+                        self.positions
+                            .iter()
+                            .filter(|&(entity, _)| self.is_indexed(entity))
+                            .map(|(entity, &position)| (entity, position))
+                            .collect(),
+                    );
+                }
+
+                let region = bounds_around(point_vec, half_extent);
+                let (candidates, complete) = self.indexed_candidates(&region, indexed);
+                let mut found = distances(candidates);
+
+                if complete {
+                    break found;
+                }
+
+                // Entities further away than `half_extent` may lie outside of the search region
+                found.retain(|&(_, distance)| distance <= half_extent);
+                if found.len() >= k {
+                    break found;
+                }
+
+                half_extent *= 2.;
+            },
+            None => distances(
+                self.positions
+                    .iter()
+                    .map(|(entity, &position)| (entity, position))
+                    .collect(),
+            ),
+        };
+
+        found.truncate(k);
+        found
+            .into_iter()
+            .map(|(entity, distance)| (entity, C::from(distance)))
+            .collect()
+    }
+
+    /// All entities whose [`Position`] lies within `region`, sorted
+    ///
+    /// Entities on the edge of the region are included.
+    #[must_use]
+    pub fn entities_in_region(&self, region: &AxisAlignedBoundingBox<C>) -> Vec<Entity> {
+        let mut found: Vec<Entity> = self
+            .candidates(region)
+            .filter(|&(_, position)| region.contains(position))
+            .map(|(entity, _)| entity)
+            .collect();
+        found.sort_unstable();
+        found
+    }
+
+    /// The entities and positions that could be within `region`, using a spatial index if one exists
+    fn candidates(
+        &self,
+        region: &AxisAlignedBoundingBox<C>,
+    ) -> Box<dyn Iterator<Item = (Entity, Position<C>)> + '_> {
+        match self.index_extent() {
+            Some((_, indexed)) => Box::new(self.indexed_candidates(region, indexed).0.into_iter()),
+            None => Box::new(
+                self.positions
+                    .iter()
+                    .map(|(entity, &position)| (entity, position)),
+            ),
+        }
+    }
+
+    /// The matching entities in the spatial index that overlap `region`,
+    /// and whether every one of the `indexed` entities was returned by the index
+    fn indexed_candidates(
+        &self,
+        region: &AxisAlignedBoundingBox<C>,
+        indexed: usize,
+    ) -> (Vec<(Entity, Position<C>)>, bool) {
+        let entities = if let Some(quadtree) = &self.quadtree {
+            quadtree.query_region(region)
+        } else if let Some(spatial_hash) = &self.spatial_hash {
+            spatial_hash.query_region(region)
+        } else {
+            Vec::new()
+        };
+        let complete = entities.len() >= indexed;
+
+        let candidates = entities
+            .into_iter()
+            .filter_map(|entity| {
+                self.positions
+                    .get(entity)
+                    .ok()
+                    .map(|(entity, &position)| (entity, position))
+            })
+            .collect();
+
+        (candidates, complete)
+    }
+
+    /// Is `entity` stored in the spatial index in use?
+    fn is_indexed(&self, entity: Entity) -> bool {
+        if let Some(quadtree) = &self.quadtree {
+            quadtree.get(entity).is_some()
+        } else if let Some(spatial_hash) = &self.spatial_hash {
+            spatial_hash.get(entity).is_some()
+        } else {
+            false
+        }
+    }
+
+    /// The largest search distance worth querying the spatial index for, before scanning each entity is cheaper
+    ///
+    /// Regions in a [`SpatialHash`] must visit every cell they cover, even if it is empty.
+    fn max_search_extent(&self, indexed: usize) -> f32 {
+        match (&self.quadtree, &self.spatial_hash) {
+            (None, Some(spatial_hash)) => spatial_hash.cell_size() * (indexed.max(1) as f32).sqrt(),
+            _ => f32::INFINITY,
+        }
+    }
+
+    /// The initial search distance and number of entities for the spatial index in use, if any
+    fn index_extent(&self) -> Option<(f32, usize)> {
+        if let Some(quadtree) = &self.quadtree {
+            let root = quadtree.root_bounds();
+            let width: f32 = (root.right - root.left).into();
+            let height: f32 = (root.top - root.bottom).into();
+            // The size of the smallest possible node
+            let half_extent = width.max(height) / 2_f32.powi(quadtree.max_depth() as i32);
+
+            Some((half_extent.max(f32::EPSILON), quadtree.len()))
+        } else {
+            self.spatial_hash
+                .as_ref()
+                .map(|spatial_hash| (spatial_hash.cell_size(), spatial_hash.len()))
+        }
+    }
+}
+
+/// The square region extending `half_extent` from `center` in each direction
+fn bounds_around<C: Coordinate>(center: Vec2, half_extent: f32) -> AxisAlignedBoundingBox<C> {
+    AxisAlignedBoundingBox {
+        left: C::from(center.x - half_extent),
+        right: C::from(center.x + half_extent),
+        bottom: C::from(center.y - half_extent),
+        top: C::from(center.y + half_extent),
+    }
+}
+
+fn sort_by_distance(found: &mut [(Entity, f32)]) {
+    found.sort_by(|(a_entity, a), (b_entity, b)| {
+        a.partial_cmp(b)
+            .unwrap_or(core::cmp::Ordering::Equal)
+            .then(a_entity.cmp(b_entity))
+    });
+}
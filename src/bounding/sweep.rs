@@ -0,0 +1,221 @@
+use super::{
+    AxisAlignedBoundingBox, BoundingCircle, BoundingPolygon, BoundingRegion, Intersects, Ray,
+    Raycast,
+};
+use crate::coordinate::Coordinate;
+use crate::kinematics::Velocity;
+use crate::orientation::Direction;
+use crate::position::Position;
+use bevy_ecs::prelude::Component;
+use bevy_math::Vec2;
+use std::time::Duration;
+
+/// Opts an entity into continuous collision detection during kinematics
+///
+/// Rather than simply applying its [`Velocity`],
+/// the entity's [`AxisAlignedBoundingBox`] is swept along its path each frame,
+/// and movement stops at the first contact with any other [`AxisAlignedBoundingBox`] that has a non-zero area.
+/// Entities without an [`AxisAlignedBoundingBox`] are swept as a single point.
+/// This prevents fast-moving entities from tunneling through thin obstacles.
+///
+/// Requires [`TwoDPlugin`](crate::plugin::TwoDPlugin) with `kinematics` enabled.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ContinuousCollision;
+
+/// The first contact made by a region that is moving through space
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimeOfImpact<C: Coordinate> {
+    /// The fraction of the movement completed before contact, between 0 and 1
+    ///
+    /// This is zero if the regions were already touching.
+    pub fraction: f32,
+    /// How far the region travels before it makes contact
+    pub offset: Position<C>,
+    /// The outward-facing [`Direction`] of the surface that was struck
+    pub normal: Direction,
+}
+
+/// A [`BoundingRegion`] that can be swept through space to find when it first touches another region
+///
+/// Both regions must be of the same type.
+///
+/// # Example
+/// ```rust
+/// use core::time::Duration;
+/// use leafwing_2d::bounding::{BoundingCircle, Sweep};
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::kinematics::Velocity;
+/// use leafwing_2d::position::Position;
+///
+/// let bullet = BoundingCircle::<F32>::new(Position::new(0.0, 0.0), 1.0);
+/// let wall = BoundingCircle::<F32>::new(Position::new(10.0, 0.0), 1.0);
+/// let velocity = Velocity { x: F32(1000.0), y: F32(0.0) };
+/// let frame = Duration::from_secs_f32(1. / 60.);
+///
+/// // Within a single frame, the bullet would pass straight through the wall
+/// let impact = bullet.time_of_impact(velocity, frame, &wall).unwrap();
+/// assert_eq!(impact.offset, Position::new(8.0, 0.0));
+/// assert!(impact.fraction < 1.0);
+/// ```
+pub trait Sweep: BoundingRegion + Sized {
+    /// Computes when `self` would first touch `other` if it moved by `displacement`
+    ///
+    /// If the regions already overlap, the [`TimeOfImpact::fraction`] is zero.
+    ///
+    /// Returns `None` if the regions never touch during this movement.
+    #[must_use]
+    fn sweep(&self, displacement: Position<Self::C>, other: &Self)
+        -> Option<TimeOfImpact<Self::C>>;
+
+    /// Computes when `self` would first touch `other` if it moved at `velocity` for `delta_time`
+    ///
+    /// The returned [`TimeOfImpact::fraction`] is the fraction of `delta_time` that passes before contact.
+    /// If the regions already overlap, it is zero.
+    ///
+    /// Returns `None` if the regions never touch during this time.
+    #[must_use]
+    fn time_of_impact(
+        &self,
+        velocity: Velocity<Self::C>,
+        delta_time: Duration,
+        other: &Self,
+    ) -> Option<TimeOfImpact<Self::C>> {
+        self.sweep(velocity * delta_time, other)
+    }
+}
+
+/// Casts a ray from `reference` along `displacement` against the Minkowski sum `expanded`
+fn sweep_minkowski<C: Coordinate, R: Raycast<C = C>>(
+    reference: Vec2,
+    displacement: Position<C>,
+    expanded: &R,
+) -> Option<TimeOfImpact<C>> {
+    let displacement_vec2 = Vec2::from(displacement);
+    let length = displacement_vec2.length();
+    let origin = Position::from(reference);
+
+    let direction: Direction = match displacement_vec2.try_into() {
+        Ok(direction) => direction,
+        // Not moving at all, so we can only be touching already
+        Err(_) => {
+            return expanded.contains(origin).then(|| TimeOfImpact {
+                fraction: 0.,
+                offset: Position::default(),
+                normal: Direction::default(),
+            })
+        }
+    };
+
+    let hit = expanded.cast_ray(Ray::new(origin, direction))?;
+    let distance: f32 = hit.distance.into();
+
+    if distance > length {
+        return None;
+    }
+
+    Some(TimeOfImpact {
+        fraction: (distance / length).clamp(0., 1.),
+        offset: (direction.unit_vector() * distance).into(),
+        normal: hit.normal,
+    })
+}
+
+impl<C: Coordinate> Sweep for AxisAlignedBoundingBox<C> {
+    fn sweep(&self, displacement: Position<C>, other: &Self) -> Option<TimeOfImpact<C>> {
+        let low = Vec2::from(self.bottom_left());
+        let high = Vec2::from(self.top_right());
+        let half_extents = (high - low) / 2.;
+
+        let other_low = Vec2::from(other.bottom_left()) - half_extents;
+        let other_high = Vec2::from(other.top_right()) + half_extents;
+        let expanded =
+            AxisAlignedBoundingBox::<C>::new(other_low.x, other_high.x, other_low.y, other_high.y);
+
+        sweep_minkowski((low + high) / 2., displacement, &expanded)
+    }
+}
+
+impl<C: Coordinate> Sweep for BoundingCircle<C> {
+    fn sweep(&self, displacement: Position<C>, other: &Self) -> Option<TimeOfImpact<C>> {
+        let expanded = BoundingCircle {
+            center: other.center,
+            radius: self.radius + other.radius,
+        };
+
+        sweep_minkowski(self.center_vec2(), displacement, &expanded)
+    }
+}
+
+impl<C: Coordinate> Sweep for BoundingPolygon<C> {
+    fn sweep(&self, displacement: Position<C>, other: &Self) -> Option<TimeOfImpact<C>> {
+        let self_points = self.vertex_vec2s();
+        let reference = *self_points.first()?;
+
+        // Wherever `reference` sits when the polygons overlap
+        let expanded = BoundingPolygon::<C>::draw_around(other.vertex_vec2s().iter().flat_map(
+            |&other_point| {
+                self_points
+                    .iter()
+                    .map(move |&self_point| Position::from(other_point + reference - self_point))
+            },
+        ));
+
+        sweep_minkowski(reference, displacement, &expanded)
+    }
+}
+
+/// Moves `aabb` as far along `displacement` as it can go before touching any of the `obstacles`
+///
+/// Obstacles that are already touching `aabb` only block movement that would push further into them.
+///
+/// Returns the actual offset that should be applied, and the first [`TimeOfImpact`] if any.
+pub(crate) fn sweep_through<'a, C: Coordinate>(
+    aabb: &AxisAlignedBoundingBox<C>,
+    displacement: Position<C>,
+    obstacles: impl IntoIterator<Item = &'a AxisAlignedBoundingBox<C>>,
+) -> (Position<C>, Option<TimeOfImpact<C>>) {
+    let first_impact = obstacles
+        .into_iter()
+        .filter_map(|obstacle| match aabb.intersects(obstacle.clone()) {
+            Intersects::No => aabb.sweep(displacement, obstacle),
+            _ => {
+                let normal = separating_normal(aabb, obstacle);
+
+                (Vec2::from(displacement).dot(normal) < 0.).then(|| TimeOfImpact {
+                    fraction: 0.,
+                    offset: Position::default(),
+                    normal: normal.try_into().unwrap_or_default(),
+                })
+            }
+        })
+        .min_by(|a, b| {
+            a.fraction
+                .partial_cmp(&b.fraction)
+                .unwrap_or(core::cmp::Ordering::Equal)
+        });
+
+    match first_impact {
+        Some(impact) => (impact.offset, Some(impact)),
+        None => (displacement, None),
+    }
+}
+
+/// The axis along which `aabb` overlaps `obstacle` the least, pointing from `obstacle` towards `aabb`
+fn separating_normal<C: Coordinate>(
+    aabb: &AxisAlignedBoundingBox<C>,
+    obstacle: &AxisAlignedBoundingBox<C>,
+) -> Vec2 {
+    let low = Vec2::from(aabb.bottom_left());
+    let high = Vec2::from(aabb.top_right());
+    let obstacle_low = Vec2::from(obstacle.bottom_left());
+    let obstacle_high = Vec2::from(obstacle.top_right());
+
+    let overlap = high.min(obstacle_high) - low.max(obstacle_low);
+    let offset = (low + high) - (obstacle_low + obstacle_high);
+
+    if overlap.x < overlap.y {
+        Vec2::new(offset.x.signum(), 0.)
+    } else {
+        Vec2::new(0., offset.y.signum())
+    }
+}
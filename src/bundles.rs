@@ -46,7 +46,9 @@ pub struct TwoDBundle<C: Coordinate> {
     pub angular_velocity: AngularVelocity,
     /// The rate at which angular velocity changes in deci-degrees per second per second
     pub angular_acceleration: AngularAcceleration,
-    /// The axis-aligned bounding box of this entity
+    /// The axis-aligned bounding box of this entity, in absolute coordinates
+    ///
+    /// Add a [`LocalBoundingBox`](crate::bounding::LocalBoundingBox) to have this follow the entity's [`Position`], [`Rotation`] and [`Scale`].
     pub aabb: AxisAlignedBoundingBox<C>,
     /// The relative scale of this entity
     pub scale: Scale<C>,
@@ -0,0 +1,314 @@
+//! Events for when the bounding regions of entities start and stop overlapping
+
+use crate::bounding::{
+    combined_bounds, contact, AxisAlignedBoundingBox, BoundingCircle, BoundingPolygon, Collider,
+    CollisionLayers, Hull, SpatialHash, ToHull,
+};
+use crate::continuous::F32;
+use crate::coordinate::Coordinate;
+use crate::plugin::TwoDSystem;
+use crate::trigger::{update_trigger_zones, TriggerEntered, TriggerExited};
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use core::marker::PhantomData;
+use std::collections::HashSet;
+
+/// Sends [`CollisionStarted`], [`CollisionOngoing`] and [`CollisionEnded`] events for every pair of overlapping entities
+///
+/// Entities collide when any of their [`AxisAlignedBoundingBox`], [`BoundingCircle`], [`BoundingPolygon`] or [`Collider`] components overlap,
+/// as long as their [`CollisionLayers`] interact.
+/// Bounding boxes with zero area, such as the default box in a [`TwoDBundle`](crate::bundles::TwoDBundle), never collide.
+/// If a [`SpatialHash`] resource exists, it is used to avoid checking every pair of entities against each other.
+///
+/// This plugin also updates each [`TriggerZone`](crate::trigger::TriggerZone) whose region is one of the built-in bounding regions,
+/// sending [`TriggerEntered`] and [`TriggerExited`] events.
+///
+/// Collisions are detected in the [`TwoDSystem::Collisions`] system label,
+/// after kinematics have been applied and bounding boxes have been updated.
+/// For this to work, `stage` must match the stage used by [`TwoDPlugin`](crate::plugin::TwoDPlugin).
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_2d::collision::{CollisionPlugin, CollisionStarted};
+/// use leafwing_2d::prelude::*;
+///
+/// fn explode_on_contact(mut events: EventReader<CollisionStarted>) {
+///     for event in events.iter() {
+///         println!("{:?} and {:?} collided!", event.0, event.1);
+///     }
+/// }
+///
+/// let mut app = App::new();
+/// app.insert_resource(bevy::core::Time::default())
+///     .add_plugin(TwoDPlugin::default())
+///     .add_plugin(CollisionPlugin::default())
+///     .add_system(explode_on_contact);
+///
+/// app.update();
+/// ```
+#[derive(Debug)]
+pub struct CollisionPlugin<C: Coordinate, UserStage: StageLabel> {
+    /// Which stage should these systems run in?
+    ///
+    /// Default: [`CoreStage::PostUpdate`]
+    pub stage: UserStage,
+    /// What [`Coordinate`] should be used?
+    ///
+    /// Default: [`F32`]
+    pub coordinate_type: PhantomData<C>,
+}
+
+impl Default for CollisionPlugin<F32, CoreStage> {
+    fn default() -> Self {
+        Self {
+            stage: CoreStage::PostUpdate,
+            coordinate_type: PhantomData,
+        }
+    }
+}
+
+impl<C: Coordinate, UserStage: StageLabel + Clone> Plugin for CollisionPlugin<C, UserStage> {
+    fn build(&self, app: &mut App) {
+        app.add_event::<CollisionStarted>()
+            .add_event::<CollisionOngoing>()
+            .add_event::<CollisionEnded>()
+            .init_resource::<ActiveCollisions>()
+            .add_system_to_stage(
+                self.stage.clone(),
+                detect_collisions::<C>
+                    .label(TwoDSystem::Collisions)
+                    .after(TwoDSystem::Kinematics)
+                    .after(TwoDSystem::SyncBounds)
+                    .after(TwoDSystem::UpdateSpatialHash),
+            )
+            .add_event::<TriggerEntered>()
+            .add_event::<TriggerExited>()
+            .add_system_set_to_stage(
+                self.stage.clone(),
+                SystemSet::new()
+                    .label(TwoDSystem::TriggerZones)
+                    .after(TwoDSystem::Kinematics)
+                    .after(TwoDSystem::SyncBounds)
+                    .with_system(update_trigger_zones::<AxisAlignedBoundingBox<C>, ()>)
+                    .with_system(update_trigger_zones::<BoundingCircle<C>, ()>)
+                    .with_system(update_trigger_zones::<BoundingPolygon<C>, ()>)
+                    .with_system(update_trigger_zones::<Collider<C>, ()>),
+            );
+    }
+}
+
+/// Sent on the first frame that two entities overlap
+///
+/// The entities are always stored in ascending order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CollisionStarted(pub Entity, pub Entity);
+
+/// Sent on each frame after the first that two entities continue to overlap
+///
+/// The entities are always stored in ascending order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CollisionOngoing(pub Entity, pub Entity);
+
+/// Sent on the first frame that two entities no longer overlap, or when either of them is despawned
+///
+/// The entities are always stored in ascending order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CollisionEnded(pub Entity, pub Entity);
+
+/// The pairs of entities that were overlapping as of the last time collisions were detected
+///
+/// Each pair is stored with the smaller [`Entity`] first.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ActiveCollisions {
+    pairs: HashSet<(Entity, Entity)>,
+}
+
+impl ActiveCollisions {
+    /// Are `a` and `b` currently colliding?
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, a: Entity, b: Entity) -> bool {
+        self.pairs.contains(&ordered(a, b))
+    }
+
+    /// Iterates over all pairs of colliding entities, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (Entity, Entity)> + '_ {
+        self.pairs.iter().copied()
+    }
+
+    /// Iterates over all entities that are colliding with `entity`, in no particular order
+    pub fn colliding_with(&self, entity: Entity) -> impl Iterator<Item = Entity> + '_ {
+        self.pairs.iter().filter_map(move |&(a, b)| {
+            if a == entity {
+                Some(b)
+            } else if b == entity {
+                Some(a)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// The number of colliding pairs
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.pairs.len()
+    }
+
+    /// Are there no colliding pairs?
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.pairs.is_empty()
+    }
+}
+
+/// Finds every pair of overlapping entities, and sends events as their contacts change
+///
+/// The [`ActiveCollisions`] resource stores which pairs were touching last time this ran.
+pub fn detect_collisions<C: Coordinate>(
+    mut active_collisions: ResMut<ActiveCollisions>,
+    spatial_hash: Option<Res<SpatialHash<C>>>,
+    query: Query<
+        (
+            Entity,
+            Option<&AxisAlignedBoundingBox<C>>,
+            Option<&BoundingCircle<C>>,
+            Option<&BoundingPolygon<C>>,
+            Option<&Collider<C>>,
+            Option<&CollisionLayers>,
+        ),
+        Or<(
+            With<AxisAlignedBoundingBox<C>>,
+            With<BoundingCircle<C>>,
+            With<BoundingPolygon<C>>,
+            With<Collider<C>>,
+        )>,
+    >,
+    mut started: EventWriter<CollisionStarted>,
+    mut ongoing: EventWriter<CollisionOngoing>,
+    mut ended: EventWriter<CollisionEnded>,
+) {
+    let candidates: Vec<(Entity, Entity)> = match spatial_hash {
+        Some(spatial_hash) => spatial_hash.potential_pairs(),
+        None => {
+            let bounds: Vec<(Entity, AxisAlignedBoundingBox<C>, CollisionLayers)> = query
+                .iter()
+                .filter_map(
+                    |(
+                        entity,
+                        maybe_aabb,
+                        maybe_circle,
+                        maybe_polygon,
+                        maybe_collider,
+                        maybe_layers,
+                    )| {
+                        Some((
+                            entity,
+                            combined_bounds(
+                                non_empty(maybe_aabb),
+                                maybe_circle,
+                                maybe_polygon,
+                                maybe_collider,
+                            )?,
+                            maybe_layers.copied().unwrap_or_default(),
+                        ))
+                    },
+                )
+                .collect();
+
+            let mut candidates = Vec::new();
+            for (i, (a, a_bounds, a_layers)) in bounds.iter().enumerate() {
+                for (b, b_bounds, b_layers) in &bounds[i + 1..] {
+                    if a_layers.interacts_with(b_layers)
+                        && a_bounds.intersection(b_bounds).is_some()
+                    {
+                        candidates.push(ordered(*a, *b));
+                    }
+                }
+            }
+            candidates
+        }
+    };
+
+    let current: HashSet<(Entity, Entity)> = candidates
+        .into_iter()
+        .filter(|&(a, b)| match (query.get(a), query.get(b)) {
+            (Ok(a), Ok(b)) => {
+                let b_hulls = hulls_of(b);
+                hulls_of(a)
+                    .iter()
+                    .any(|a_hull| b_hulls.iter().any(|b_hull| hulls_overlap(a_hull, b_hull)))
+            }
+            _ => false,
+        })
+        .collect();
+
+    // Sorting keeps the order of events deterministic
+    let mut new_pairs: Vec<_> = current.difference(&active_collisions.pairs).collect();
+    new_pairs.sort_unstable();
+    started.send_batch(new_pairs.into_iter().map(|&(a, b)| CollisionStarted(a, b)));
+
+    let mut continuing_pairs: Vec<_> = current.intersection(&active_collisions.pairs).collect();
+    continuing_pairs.sort_unstable();
+    ongoing.send_batch(
+        continuing_pairs
+            .into_iter()
+            .map(|&(a, b)| CollisionOngoing(a, b)),
+    );
+
+    let mut ended_pairs: Vec<_> = active_collisions.pairs.difference(&current).collect();
+    ended_pairs.sort_unstable();
+    ended.send_batch(ended_pairs.into_iter().map(|&(a, b)| CollisionEnded(a, b)));
+
+    // Avoid triggering change detection needlessly
+    if active_collisions.pairs != current {
+        active_collisions.pairs = current;
+    }
+}
+
+/// The [`Hull`] of every bounding component of an entity
+fn hulls_of<C: Coordinate>(
+    (_, maybe_aabb, maybe_circle, maybe_polygon, maybe_collider, _): (
+        Entity,
+        Option<&AxisAlignedBoundingBox<C>>,
+        Option<&BoundingCircle<C>>,
+        Option<&BoundingPolygon<C>>,
+        Option<&Collider<C>>,
+        Option<&CollisionLayers>,
+    ),
+) -> Vec<Hull> {
+    non_empty(maybe_aabb)
+        .map(ToHull::hull)
+        .into_iter()
+        .chain(maybe_circle.map(ToHull::hull))
+        .chain(maybe_polygon.map(ToHull::hull))
+        .chain(maybe_collider.map(ToHull::hull))
+        .collect()
+}
+
+/// Discards bounding boxes with zero area, which are left at their default value when an entity has no real bounds
+#[inline]
+fn non_empty<C: Coordinate>(
+    maybe_aabb: Option<&AxisAlignedBoundingBox<C>>,
+) -> Option<&AxisAlignedBoundingBox<C>> {
+    maybe_aabb.filter(|aabb| aabb.area() != C::ZERO)
+}
+
+/// Do these two hulls overlap or touch?
+fn hulls_overlap(a: &Hull, b: &Hull) -> bool {
+    contact(a, b).map_or(false, |contact| contact.distance <= 0.)
+}
+
+/// Orders the pair of entities so that the smaller one is first
+#[inline]
+fn ordered(a: Entity, b: Entity) -> (Entity, Entity) {
+    if a < b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
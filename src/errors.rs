@@ -11,3 +11,7 @@ use derive_more::{Display, Error};
 /// In almost all cases, the correct way to handle this error is to simply not change the rotation.
 #[derive(Debug, Clone, Copy, Error, Display, PartialEq, Eq)]
 pub struct NearlySingularConversion;
+
+/// No positions were supplied to draw a bounding region around
+#[derive(Debug, Clone, Copy, Error, Display, PartialEq, Eq)]
+pub struct EmptyRegion;
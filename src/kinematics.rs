@@ -4,7 +4,18 @@ use crate::coordinate::Coordinate;
 use bevy_ecs::component::Component;
 use derive_more::{Add, AddAssign, Sub, SubAssign};
 
+pub use damping::{AngularDamping, Damping, LinearDamping};
+pub use forces::{AngularImpulse, Force, Impulse, Mass, MomentOfInertia, Torque};
+pub use integrator::{AccelerationField, Integrator};
 pub use kinematic_trait::Kinematic;
+pub use limits::{
+    MaxAcceleration, MaxAccelerationPerAxis, MaxAngularVelocity, MaxSpeed, MaxSpeedPerAxis,
+};
+
+mod damping;
+mod forces;
+mod integrator;
+mod limits;
 
 /// The rate of change of [`Position<C>`]
 ///
@@ -31,13 +42,16 @@ pub struct Acceleration<C: Coordinate> {
 /// The rate of change of [`Rotation`]
 ///
 /// When used with [`angular_kinematics`](systems::angular_kinematics), the units are tenth of a degree per second
+///
+/// Unlike [`Rotation`], this is not restricted to whole tenths of a degree,
+/// so slow rotations are applied smoothly over many frames.
 #[derive(Component, Default, Clone, Copy, Debug, PartialEq, Add, Sub, AddAssign, SubAssign)]
 pub struct AngularVelocity {
     /// Tenth of a degree
     ///
     /// Positive is [`Clockwise`](crate::orientation::RotationDirection::Clockwise).
     /// Negative is [`CounterClockwise`](crate::orientation::RotationDirection::CounterClockwise).
-    pub deci_degrees: isize,
+    pub deci_degrees: f32,
 }
 
 /// The rate of change of [`AngularVelocity`]
@@ -49,7 +63,7 @@ pub struct AngularAcceleration {
     ///
     /// Positive is [`Clockwise`](crate::orientation::RotationDirection::Clockwise).
     /// Negative is [`CounterClockwise`](crate::orientation::RotationDirection::CounterClockwise).
-    pub deci_degrees: isize,
+    pub deci_degrees: f32,
 }
 
 mod kinematic_trait {
@@ -160,66 +174,68 @@ mod kinematic_trait {
 
     impl Kinematic for AngularVelocity {
         /// Tenths of a degree
-        type M = isize;
+        type M = f32;
         type D = RotationDirection;
 
         fn new<IM: Into<Self::M>, ID: Into<Self::D>>(magnitude: IM, direction: ID) -> Self {
-            let magnitude: isize = magnitude.into();
+            let magnitude: f32 = magnitude.into();
             let direction: RotationDirection = direction.into();
 
             AngularVelocity {
-                deci_degrees: magnitude * direction.sign(),
+                deci_degrees: magnitude * direction.sign() as f32,
             }
         }
 
-        fn magnitude(&self) -> isize {
+        fn magnitude(&self) -> f32 {
             self.deci_degrees.abs()
         }
 
-        fn magnitude_squared(&self) -> isize {
+        fn magnitude_squared(&self) -> f32 {
             self.deci_degrees * self.deci_degrees
         }
 
         fn direction(&self) -> Option<RotationDirection> {
             let degrees = self.deci_degrees;
-            match degrees {
-                _neg if degrees < 0 => Some(RotationDirection::CounterClockwise),
-                _zero if degrees == 0 => None,
-                _pos if degrees > 0 => Some(RotationDirection::Clockwise),
-                _ => unreachable!(),
+            if degrees < 0. {
+                Some(RotationDirection::CounterClockwise)
+            } else if degrees > 0. {
+                Some(RotationDirection::Clockwise)
+            } else {
+                None
             }
         }
     }
 
     impl Kinematic for AngularAcceleration {
         /// Tenths of a degree
-        type M = isize;
+        type M = f32;
         type D = RotationDirection;
 
         fn new<IM: Into<Self::M>, ID: Into<Self::D>>(magnitude: IM, direction: ID) -> Self {
-            let magnitude: isize = magnitude.into();
+            let magnitude: f32 = magnitude.into();
             let direction: RotationDirection = direction.into();
 
             AngularAcceleration {
-                deci_degrees: magnitude * direction.sign(),
+                deci_degrees: magnitude * direction.sign() as f32,
             }
         }
 
-        fn magnitude(&self) -> isize {
+        fn magnitude(&self) -> f32 {
             self.deci_degrees.abs()
         }
 
-        fn magnitude_squared(&self) -> isize {
+        fn magnitude_squared(&self) -> f32 {
             self.deci_degrees * self.deci_degrees
         }
 
         fn direction(&self) -> Option<RotationDirection> {
             let degrees = self.deci_degrees;
-            match degrees {
-                _neg if degrees < 0 => Some(RotationDirection::CounterClockwise),
-                _zero if degrees == 0 => None,
-                _pos if degrees > 0 => Some(RotationDirection::Clockwise),
-                _ => unreachable!(),
+            if degrees < 0. {
+                Some(RotationDirection::CounterClockwise)
+            } else if degrees > 0. {
+                Some(RotationDirection::Clockwise)
+            } else {
+                None
             }
         }
     }
@@ -231,34 +247,542 @@ mod kinematic_trait {
 pub mod systems {
     use super::*;
 
+    use crate::bounding::{
+        separation, sweep_through, AxisAlignedBoundingBox, CollisionLayers, CollisionResponse,
+        ContinuousCollision, LocalBoundingBox, StaticCollider,
+    };
+    use crate::kinematics::limits::{clamp_component, clamp_magnitude};
     use crate::orientation::Rotation;
     use crate::position::Position;
+    use crate::scale::Scale;
+    use crate::timestep::{kinematics_delta, FixedTimestep};
     use bevy_core::Time;
     use bevy_ecs::prelude::*;
+    use bevy_math::Vec2;
+    use core::time::Duration;
+    use std::collections::HashMap;
+
+    /// The optional limits on the linear motion of an entity
+    type LinearLimits<'a, C> = (
+        Option<&'a MaxSpeed<C>>,
+        Option<&'a MaxSpeedPerAxis<C>>,
+        Option<&'a MaxAcceleration<C>>,
+        Option<&'a MaxAccelerationPerAxis<C>>,
+    );
 
     /// Applies [`Acceleration`] and [`Velocity`] according to elapsed [`Time`]
+    ///
+    /// If a [`FixedTimestep`] resource exists, each run covers one of its steps instead.
+    /// Entities are advanced using their [`Integrator`] component if they have one,
+    /// or the [`Integrator`] resource otherwise, along with any [`AccelerationField`].
+    ///
+    /// Any [`Impulse`] is applied to the [`Velocity`] before the step and then reset to zero,
+    /// while any [`Force`] is added to the [`Acceleration`] during it.
+    /// Both are scaled by the entity's [`Mass`].
+    /// Forces are reset by [`clear_forces`] once every step this frame has run.
+    /// Finally, the [`Velocity`] is slowed by any [`LinearDamping`].
+    ///
+    /// The acceleration is limited by any [`MaxAcceleration`] or [`MaxAccelerationPerAxis`],
+    /// while the velocity and distance moved during the step are limited by any [`MaxSpeed`] or [`MaxSpeedPerAxis`].
+    ///
+    /// Entities with the [`ContinuousCollision`] component are skipped:
+    /// they are handled by [`continuous_linear_kinematics`] instead.
     pub fn linear_kinematics<C: Coordinate>(
         time: Res<Time>,
-        mut query: Query<(&mut Position<C>, &mut Velocity<C>, &Acceleration<C>)>,
+        fixed_timestep: Option<Res<FixedTimestep>>,
+        default_integrator: Option<Res<Integrator>>,
+        mut query: Query<
+            (
+                &mut Position<C>,
+                &mut Velocity<C>,
+                &Acceleration<C>,
+                Option<&Integrator>,
+                Option<&AccelerationField<C>>,
+                (Option<&Force<C>>, Option<&mut Impulse<C>>, Option<&Mass>),
+                Option<&LinearDamping>,
+                LinearLimits<'_, C>,
+            ),
+            Without<ContinuousCollision>,
+        >,
+    ) {
+        let delta_time = kinematics_delta(&time, fixed_timestep.as_deref());
+        let default_integrator = default_integrator.as_deref().copied().unwrap_or_default();
+
+        for (
+            mut position,
+            mut velocity,
+            &acceleration,
+            maybe_integrator,
+            maybe_field,
+            forces,
+            maybe_damping,
+            limits,
+        ) in query.iter_mut()
+        {
+            let integrator = maybe_integrator.copied().unwrap_or(default_integrator);
+            let (initial_velocity, acceleration) = apply_forces(*velocity, acceleration, forces);
+            let acceleration = limit_acceleration(acceleration, limits);
+            let (displacement, new_velocity) = integrator.integrate(
+                *position,
+                initial_velocity,
+                acceleration,
+                maybe_field,
+                delta_time,
+            );
+            let new_velocity = damp_velocity(new_velocity, maybe_damping, delta_time);
+            let (displacement, new_velocity) =
+                limit_speed(displacement, new_velocity, limits, delta_time);
+
+            *velocity = new_velocity;
+            *position += displacement;
+        }
+    }
+
+    /// Applies [`Acceleration`] and [`Velocity`] to entities with [`ContinuousCollision`], stopping them at the first contact
+    ///
+    /// Each entity's [`AxisAlignedBoundingBox`] is swept along its path for this frame
+    /// against the [`AxisAlignedBoundingBox`] of every other entity whose [`CollisionLayers`] interact with its own.
+    /// Entities without an [`AxisAlignedBoundingBox`] are swept as a single point,
+    /// and bounding boxes with zero area (such as the default box in a [`TwoDBundle`](crate::bundles::TwoDBundle)) are never obstacles.
+    /// If it would make contact, the entity is moved until it is just touching the obstacle,
+    /// and its [`Velocity`] is set to zero.
+    /// The [`AxisAlignedBoundingBox`] is moved along with the entity's [`Position`].
+    ///
+    /// If a [`FixedTimestep`] resource exists, each run covers one of its steps instead of the elapsed [`Time`].
+    /// Movement is integrated in the same way as [`linear_kinematics`], including any forces, damping and limits.
+    pub fn continuous_linear_kinematics<C: Coordinate>(
+        time: Res<Time>,
+        fixed_timestep: Option<Res<FixedTimestep>>,
+        default_integrator: Option<Res<Integrator>>,
+        mut queries: ParamSet<(
+            Query<(Entity, &AxisAlignedBoundingBox<C>, Option<&CollisionLayers>)>,
+            Query<
+                (
+                    Entity,
+                    &mut Position<C>,
+                    &mut Velocity<C>,
+                    &Acceleration<C>,
+                    Option<&mut AxisAlignedBoundingBox<C>>,
+                    Option<&CollisionLayers>,
+                    Option<&Integrator>,
+                    Option<&AccelerationField<C>>,
+                    (Option<&Force<C>>, Option<&mut Impulse<C>>, Option<&Mass>),
+                    Option<&LinearDamping>,
+                    LinearLimits<'_, C>,
+                ),
+                With<ContinuousCollision>,
+            >,
+        )>,
+    ) {
+        let delta_time = kinematics_delta(&time, fixed_timestep.as_deref());
+        let default_integrator = default_integrator.as_deref().copied().unwrap_or_default();
+
+        let obstacles: Vec<(Entity, AxisAlignedBoundingBox<C>, CollisionLayers)> = queries
+            .p0()
+            .iter()
+            .filter(|(_, aabb, _)| aabb.area() != C::ZERO)
+            .map(|(entity, aabb, maybe_layers)| {
+                (
+                    entity,
+                    aabb.clone(),
+                    maybe_layers.copied().unwrap_or_default(),
+                )
+            })
+            .collect();
+
+        for (
+            entity,
+            mut position,
+            mut velocity,
+            &acceleration,
+            mut maybe_aabb,
+            maybe_layers,
+            maybe_integrator,
+            maybe_field,
+            forces,
+            maybe_damping,
+            limits,
+        ) in queries.p1().iter_mut()
+        {
+            let integrator = maybe_integrator.copied().unwrap_or(default_integrator);
+            let (initial_velocity, acceleration) = apply_forces(*velocity, acceleration, forces);
+            let acceleration = limit_acceleration(acceleration, limits);
+            let (displacement, new_velocity) = integrator.integrate(
+                *position,
+                initial_velocity,
+                acceleration,
+                maybe_field,
+                delta_time,
+            );
+            let new_velocity = damp_velocity(new_velocity, maybe_damping, delta_time);
+            let (displacement, new_velocity) =
+                limit_speed(displacement, new_velocity, limits, delta_time);
+            *velocity = new_velocity;
+            let layers = maybe_layers.copied().unwrap_or_default();
+
+            let other_obstacles = obstacles
+                .iter()
+                .filter(|(other_entity, _, other_layers)| {
+                    *other_entity != entity && layers.interacts_with(other_layers)
+                })
+                .map(|(_, obstacle, _)| obstacle);
+
+            let swept_bounds = match maybe_aabb.as_deref() {
+                Some(aabb) => aabb.clone(),
+                None => AxisAlignedBoundingBox::from_size(*position, C::ZERO, C::ZERO),
+            };
+            let (offset, impact) = sweep_through(&swept_bounds, displacement, other_obstacles);
+
+            if impact.is_some() {
+                *velocity = Velocity::default();
+            }
+
+            if offset != Position::default() {
+                *position += offset;
+                if let Some(aabb) = maybe_aabb.as_deref_mut() {
+                    *aabb += offset;
+                }
+            }
+        }
+    }
+
+    /// Separates entities with [`CollisionResponse`] from any [`StaticCollider`] they overlap, adjusting their [`Velocity`]
+    ///
+    /// This should run after [`linear_kinematics`], once entities have moved for this frame.
+    /// Each entity's bounds are taken from its [`LocalBoundingBox`] if it has one, or its [`AxisAlignedBoundingBox`] otherwise.
+    /// Obstacles are resolved one at a time, so entities pushed into a second obstacle will be pushed back out again.
+    pub fn collision_response<C: Coordinate>(
+        obstacle_query: Query<
+            (&AxisAlignedBoundingBox<C>, Option<&CollisionLayers>),
+            With<StaticCollider>,
+        >,
+        mut query: Query<
+            (
+                &mut Position<C>,
+                &mut Velocity<C>,
+                &mut AxisAlignedBoundingBox<C>,
+                &CollisionResponse,
+                Option<&LocalBoundingBox<C>>,
+                Option<&Rotation>,
+                Option<&Scale<C>>,
+                Option<&CollisionLayers>,
+            ),
+            Without<StaticCollider>,
+        >,
     ) {
-        let delta_time = time.delta();
-        for (mut position, mut velocity, acceleration) in query.iter_mut() {
-            *velocity += *acceleration * delta_time;
-            *position += *velocity * delta_time;
+        for (
+            mut position,
+            mut velocity,
+            mut aabb,
+            response,
+            maybe_local_bounds,
+            maybe_rotation,
+            maybe_scale,
+            maybe_layers,
+        ) in query.iter_mut()
+        {
+            let layers = maybe_layers.copied().unwrap_or_default();
+            let mut bounds = match maybe_local_bounds {
+                Some(local_bounds) => {
+                    local_bounds.world_bounds(*position, maybe_rotation, maybe_scale)
+                }
+                None => aabb.clone(),
+            };
+
+            let initial_velocity = Vec2::new(velocity.x.into(), velocity.y.into());
+            let mut new_velocity = initial_velocity;
+            let mut offset = Vec2::ZERO;
+
+            for (obstacle, maybe_obstacle_layers) in obstacle_query.iter() {
+                if !layers.interacts_with(&maybe_obstacle_layers.copied().unwrap_or_default()) {
+                    continue;
+                }
+
+                if let Some(separation) = separation(&bounds, obstacle) {
+                    bounds += Position::from(separation);
+                    offset += separation;
+                    new_velocity = response.respond(new_velocity, separation.normalize());
+                }
+            }
+
+            if offset != Vec2::ZERO {
+                let offset = Position::from(offset);
+                *position += offset;
+                *aabb += offset;
+            }
+
+            if new_velocity != initial_velocity {
+                *velocity = Velocity {
+                    x: C::from(new_velocity.x),
+                    y: C::from(new_velocity.y),
+                };
+            }
         }
     }
 
     /// Applies [`AngularAcceleration`] and [`AngularVelocity`] according to elapsed [`Time`]
+    ///
+    /// If a [`FixedTimestep`] resource exists, each run covers one of its steps instead.
+    /// Entities are advanced using their [`Integrator`] component if they have one,
+    /// or the [`Integrator`] resource otherwise.
+    ///
+    /// Any [`AngularImpulse`] is applied to the [`AngularVelocity`] before the step and then reset to zero,
+    /// while any [`Torque`] is added to the [`AngularAcceleration`] during it.
+    /// Both are scaled by the entity's [`MomentOfInertia`].
+    /// Torques are reset by [`clear_forces`] once every step this frame has run.
+    /// Finally, the [`AngularVelocity`] is slowed by any [`AngularDamping`],
+    /// and it and the rotation during the step are limited by any [`MaxAngularVelocity`].
+    ///
+    /// [`Rotation`] is stored in whole tenths of a degree,
+    /// so any fractional rotation is carried over to the next run rather than being lost.
     pub fn angular_kinematics(
         time: Res<Time>,
-        mut query: Query<(&mut Rotation, &mut AngularVelocity, &AngularAcceleration)>,
+        fixed_timestep: Option<Res<FixedTimestep>>,
+        default_integrator: Option<Res<Integrator>>,
+        mut remainders: Local<HashMap<Entity, f32>>,
+        mut query: Query<(
+            Entity,
+            &mut Rotation,
+            &mut AngularVelocity,
+            &AngularAcceleration,
+            Option<&Integrator>,
+            (
+                Option<&Torque>,
+                Option<&mut AngularImpulse>,
+                Option<&MomentOfInertia>,
+            ),
+            Option<&AngularDamping>,
+            Option<&MaxAngularVelocity>,
+        )>,
     ) {
-        let delta_time = time.delta();
-        for (mut position, mut velocity, acceleration) in query.iter_mut() {
-            *velocity += *acceleration * delta_time;
-            *position += *velocity * delta_time;
+        let delta_time = kinematics_delta(&time, fixed_timestep.as_deref());
+        let default_integrator = default_integrator.as_deref().copied().unwrap_or_default();
+        // Only entities that are still rotating keep their remainder
+        let previous_remainders = core::mem::take(&mut *remainders);
+
+        for (
+            entity,
+            mut rotation,
+            mut velocity,
+            &acceleration,
+            maybe_integrator,
+            torques,
+            maybe_damping,
+            maybe_max_angular_velocity,
+        ) in query.iter_mut()
+        {
+            let integrator = maybe_integrator.copied().unwrap_or(default_integrator);
+            let (initial_velocity, acceleration) = apply_torques(*velocity, acceleration, torques);
+            let (change_in_deci_degrees, new_velocity) =
+                integrator.integrate_angular(initial_velocity, acceleration, delta_time);
+
+            let new_velocity = damp_angular_velocity(new_velocity, maybe_damping, delta_time);
+            let (change_in_deci_degrees, new_velocity) = match maybe_max_angular_velocity {
+                Some(&max_angular_velocity) => {
+                    let max_change = max_angular_velocity.deci_degrees * delta_time.as_secs_f32();
+
+                    (
+                        change_in_deci_degrees.max(-max_change).min(max_change),
+                        max_angular_velocity.clamp(new_velocity),
+                    )
+                }
+                None => (change_in_deci_degrees, new_velocity),
+            };
+            if *velocity != new_velocity {
+                *velocity = new_velocity;
+            }
+
+            let total_change = change_in_deci_degrees
+                + previous_remainders
+                    .get(&entity)
+                    .copied()
+                    .unwrap_or_default();
+            let whole_change = total_change.round();
+
+            if whole_change != 0. {
+                *rotation += Rotation::from_deci_degrees(whole_change);
+            }
+
+            let remainder = total_change - whole_change;
+            if remainder != 0. {
+                remainders.insert(entity, remainder);
+            }
         }
     }
+
+    /// Resets every [`Force`] and [`Torque`] to zero, once the kinematics systems have run for this frame
+    ///
+    /// Forces and torques act for the whole of every step that runs in the frame they were applied in,
+    /// so they should be set (or added to) each frame that they should act for.
+    /// With a [`FixedTimestep`], [`TwoDPlugin`](crate::plugin::TwoDPlugin) runs this after the last step of each frame,
+    /// even when no steps are run.
+    pub fn clear_forces<C: Coordinate>(
+        mut force_query: Query<&mut Force<C>>,
+        mut torque_query: Query<&mut Torque>,
+    ) {
+        for mut force in force_query.iter_mut() {
+            // Avoid triggering change detection needlessly
+            if *force != Force::default() {
+                *force = Force::default();
+            }
+        }
+
+        for mut torque in torque_query.iter_mut() {
+            if *torque != Torque::default() {
+                *torque = Torque::default();
+            }
+        }
+    }
+
+    /// Applies any [`Impulse`] and [`Force`] acting on an entity, resetting the impulse
+    ///
+    /// Returns the [`Velocity`] at the start of this step, and the [`Acceleration`] during it.
+    fn apply_forces<C: Coordinate>(
+        velocity: Velocity<C>,
+        acceleration: Acceleration<C>,
+        (maybe_force, maybe_impulse, maybe_mass): (
+            Option<&Force<C>>,
+            Option<Mut<Impulse<C>>>,
+            Option<&Mass>,
+        ),
+    ) -> (Velocity<C>, Acceleration<C>) {
+        let mass = maybe_mass.copied().unwrap_or_default();
+        let mut velocity = velocity;
+        let mut acceleration = acceleration;
+
+        if let Some(mut impulse) = maybe_impulse {
+            if *impulse != Impulse::default() {
+                velocity += *impulse / mass;
+                *impulse = Impulse::default();
+            }
+        }
+
+        if let Some(&force) = maybe_force {
+            if force != Force::default() {
+                acceleration += force / mass;
+            }
+        }
+
+        (velocity, acceleration)
+    }
+
+    /// Applies any [`AngularImpulse`] and [`Torque`] acting on an entity, resetting the impulse
+    ///
+    /// Returns the [`AngularVelocity`] at the start of this step, and the [`AngularAcceleration`] during it.
+    fn apply_torques(
+        velocity: AngularVelocity,
+        acceleration: AngularAcceleration,
+        (maybe_torque, maybe_impulse, maybe_moment_of_inertia): (
+            Option<&Torque>,
+            Option<Mut<AngularImpulse>>,
+            Option<&MomentOfInertia>,
+        ),
+    ) -> (AngularVelocity, AngularAcceleration) {
+        let moment_of_inertia = maybe_moment_of_inertia.copied().unwrap_or_default();
+        let mut velocity = velocity;
+        let mut acceleration = acceleration;
+
+        if let Some(mut impulse) = maybe_impulse {
+            if *impulse != AngularImpulse::default() {
+                velocity += *impulse / moment_of_inertia;
+                *impulse = AngularImpulse::default();
+            }
+        }
+
+        if let Some(&torque) = maybe_torque {
+            if torque != Torque::default() {
+                acceleration += torque / moment_of_inertia;
+            }
+        }
+
+        (velocity, acceleration)
+    }
+
+    /// Slows the `velocity` according to the [`LinearDamping`], if any, without changing its direction
+    fn damp_velocity<C: Coordinate>(
+        velocity: Velocity<C>,
+        maybe_damping: Option<&LinearDamping>,
+        delta_time: Duration,
+    ) -> Velocity<C> {
+        let damping = match maybe_damping {
+            Some(damping) => damping.0,
+            None => return velocity,
+        };
+
+        let speed: f32 = velocity.magnitude().into();
+        if speed == 0. {
+            return velocity;
+        }
+
+        velocity * (damping.damp(speed, delta_time) / speed)
+    }
+
+    /// Slows the `velocity` according to the [`AngularDamping`], if any, without reversing its direction
+    fn damp_angular_velocity(
+        velocity: AngularVelocity,
+        maybe_damping: Option<&AngularDamping>,
+        delta_time: Duration,
+    ) -> AngularVelocity {
+        match maybe_damping {
+            Some(damping) => AngularVelocity {
+                deci_degrees: damping.0.damp(velocity.deci_degrees, delta_time),
+            },
+            None => velocity,
+        }
+    }
+
+    /// Limits the `acceleration` of an entity during a step, according to its [`MaxAcceleration`] and [`MaxAccelerationPerAxis`]
+    fn limit_acceleration<C: Coordinate>(
+        acceleration: Acceleration<C>,
+        (_, _, maybe_max_acceleration, maybe_max_acceleration_per_axis): LinearLimits<C>,
+    ) -> Acceleration<C> {
+        let mut acceleration = acceleration;
+
+        if let Some(max_acceleration) = maybe_max_acceleration {
+            acceleration = max_acceleration.clamp(acceleration);
+        }
+
+        if let Some(max_acceleration_per_axis) = maybe_max_acceleration_per_axis {
+            acceleration = max_acceleration_per_axis.clamp(acceleration);
+        }
+
+        acceleration
+    }
+
+    /// Limits the `displacement` and `velocity` of an entity after a step, according to its [`MaxSpeed`] and [`MaxSpeedPerAxis`]
+    ///
+    /// The displacement is limited to the distance that could be travelled at the maximum speed during the step.
+    fn limit_speed<C: Coordinate>(
+        displacement: Position<C>,
+        velocity: Velocity<C>,
+        (maybe_max_speed, maybe_max_speed_per_axis, _, _): LinearLimits<C>,
+        delta_time: Duration,
+    ) -> (Position<C>, Velocity<C>) {
+        let seconds = delta_time.as_secs_f32();
+        let mut displacement = displacement;
+        let mut velocity = velocity;
+
+        if let Some(&max_speed) = maybe_max_speed {
+            velocity = max_speed.clamp(velocity);
+
+            let max_distance: f32 = max_speed.0.into();
+            let (x, y) = clamp_magnitude(displacement.x, displacement.y, max_distance * seconds);
+            displacement = Position { x, y };
+        }
+
+        if let Some(&max_speed_per_axis) = maybe_max_speed_per_axis {
+            velocity = max_speed_per_axis.clamp(velocity);
+
+            let (max_x, max_y): (f32, f32) =
+                (max_speed_per_axis.x.into(), max_speed_per_axis.y.into());
+            displacement = Position {
+                x: clamp_component(displacement.x, max_x * seconds),
+                y: clamp_component(displacement.y, max_y * seconds),
+            };
+        }
+
+        (displacement, velocity)
+    }
 }
 
 mod mul_f32 {
@@ -322,10 +846,8 @@ mod mul_f32 {
         type Output = AngularVelocity;
 
         fn mul(self, rhs: f32) -> Self::Output {
-            let scale = rhs.round() as isize;
-
             Self {
-                deci_degrees: self.deci_degrees * scale,
+                deci_degrees: self.deci_degrees * rhs,
             }
         }
     }
@@ -334,10 +856,8 @@ mod mul_f32 {
         type Output = AngularVelocity;
 
         fn mul(self, rhs: AngularVelocity) -> AngularVelocity {
-            let scale = self.round() as isize;
-
             AngularVelocity {
-                deci_degrees: rhs.deci_degrees * scale,
+                deci_degrees: rhs.deci_degrees * self,
             }
         }
     }
@@ -346,10 +866,8 @@ mod mul_f32 {
         type Output = AngularAcceleration;
 
         fn mul(self, rhs: f32) -> AngularAcceleration {
-            let scale = rhs.round() as isize;
-
             Self {
-                deci_degrees: self.deci_degrees * scale,
+                deci_degrees: self.deci_degrees * rhs,
             }
         }
     }
@@ -358,10 +876,8 @@ mod mul_f32 {
         type Output = AngularAcceleration;
 
         fn mul(self, rhs: AngularAcceleration) -> AngularAcceleration {
-            let scale = self.round() as isize;
-
             AngularAcceleration {
-                deci_degrees: rhs.deci_degrees * scale,
+                deci_degrees: rhs.deci_degrees * self,
             }
         }
     }
@@ -426,14 +942,14 @@ mod mul_duration {
         }
     }
 
+    /// The rotation is rounded to the nearest tenth of a degree
+    ///
+    /// Use [`angular_kinematics`](super::systems::angular_kinematics) to accumulate rotation without losing the remainder.
     impl Mul<Duration> for AngularVelocity {
         type Output = Rotation;
 
         fn mul(self, rhs: Duration) -> Rotation {
-            let scale = rhs.as_secs_f32() as isize;
-            let new_deci_degrees = (self.deci_degrees * scale) % 3600;
-
-            Rotation::new(new_deci_degrees as u16)
+            Rotation::from_deci_degrees(self.deci_degrees * rhs.as_secs_f32())
         }
     }
 
@@ -441,10 +957,7 @@ mod mul_duration {
         type Output = Rotation;
 
         fn mul(self, rhs: AngularVelocity) -> Rotation {
-            let scale = self.as_secs_f32() as isize;
-            let new_deci_degrees = (rhs.deci_degrees * scale) % 3600;
-
-            Rotation::new(new_deci_degrees as u16)
+            Rotation::from_deci_degrees(rhs.deci_degrees * self.as_secs_f32())
         }
     }
 
@@ -452,10 +965,8 @@ mod mul_duration {
         type Output = AngularVelocity;
 
         fn mul(self, rhs: Duration) -> AngularVelocity {
-            let scale = rhs.as_secs_f32() as isize;
-
             AngularVelocity {
-                deci_degrees: self.deci_degrees * scale,
+                deci_degrees: self.deci_degrees * rhs.as_secs_f32(),
             }
         }
     }
@@ -464,10 +975,8 @@ mod mul_duration {
         type Output = AngularVelocity;
 
         fn mul(self, rhs: AngularAcceleration) -> AngularVelocity {
-            let scale = self.as_secs_f32() as isize;
-
             AngularVelocity {
-                deci_degrees: rhs.deci_degrees * scale,
+                deci_degrees: rhs.deci_degrees * self.as_secs_f32(),
             }
         }
     }
@@ -0,0 +1,79 @@
+use bevy_ecs::component::Component;
+use core::time::Duration;
+
+/// How quickly a speed decays over time
+///
+/// Each model is applied using the exact solution for the elapsed time,
+/// so the result is the same regardless of the frame rate or timestep.
+/// The direction of motion is never changed: damping can only slow things down to a stop.
+/// Coefficients should not be negative: any negative coefficient is treated as zero.
+///
+/// # Example
+/// ```rust
+/// use core::time::Duration;
+/// use leafwing_2d::kinematics::Damping;
+///
+/// let one_second = Duration::from_secs(1);
+/// let half_second = Duration::from_millis(500);
+///
+/// // Loses 3 units of speed per second
+/// assert_eq!(Damping::Linear(3.0).damp(10.0, one_second), 7.0);
+/// assert_eq!(Damping::Linear(3.0).damp(2.0, one_second), 0.0);
+///
+/// // Two half-steps have the same effect as one full step
+/// let damping = Damping::Quadratic(0.5);
+/// let after_two_steps = damping.damp(damping.damp(10.0, half_second), half_second);
+/// assert!((after_two_steps - damping.damp(10.0, one_second)).abs() < 1e-5);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Damping {
+    /// Speed decreases by a constant amount per second, like friction against the ground
+    ///
+    /// The rate of change of speed is `-coefficient`.
+    Linear(f32),
+    /// Speed decreases in proportion to its square, like air resistance at high speed
+    ///
+    /// The rate of change of speed is `-coefficient * speed * speed`.
+    Quadratic(f32),
+    /// Speed decreases in proportion to itself, like moving through a thick fluid
+    ///
+    /// The rate of change of speed is `-coefficient * speed`,
+    /// so a fixed fraction of the speed is lost each second.
+    Exponential(f32),
+}
+
+impl Damping {
+    /// The speed after damping a `speed` for `delta_time`
+    ///
+    /// Negative speeds are damped towards zero in the same way as positive ones,
+    /// and the speed is never increased.
+    #[must_use]
+    pub fn damp(self, speed: f32, delta_time: Duration) -> f32 {
+        let seconds = delta_time.as_secs_f32();
+        let magnitude = speed.abs();
+
+        let damped_magnitude = match self {
+            Damping::Linear(coefficient) => (magnitude - coefficient.max(0.) * seconds).max(0.),
+            Damping::Quadratic(coefficient) => {
+                magnitude / (1. + coefficient.max(0.) * magnitude * seconds)
+            }
+            Damping::Exponential(coefficient) => magnitude * (-coefficient.max(0.) * seconds).exp(),
+        };
+
+        damped_magnitude.copysign(speed)
+    }
+}
+
+/// Slows down the [`Velocity`](super::Velocity) of an entity over time
+///
+/// This is applied by the kinematics systems at the end of each step,
+/// and reduces the magnitude of the velocity without changing its direction.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct LinearDamping(pub Damping);
+
+/// Slows down the [`AngularVelocity`](super::AngularVelocity) of an entity over time
+///
+/// This is applied by the kinematics systems at the end of each step,
+/// and never reverses the direction of rotation.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct AngularDamping(pub Damping);
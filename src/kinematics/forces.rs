@@ -0,0 +1,200 @@
+use super::{Acceleration, AngularAcceleration, AngularVelocity, Velocity};
+use crate::coordinate::Coordinate;
+use bevy_ecs::component::Component;
+use core::ops::Div;
+use derive_more::{Add, AddAssign, Sub, SubAssign};
+
+/// How strongly an entity resists changes to its [`Velocity`]
+///
+/// [`Force`] and [`Impulse`] are divided by this to get the change in motion.
+/// Entities without this component are treated as having a mass of 1.
+/// Use [`Mass::INFINITE`] for entities that should not be moved by forces at all.
+///
+/// The mass should be greater than zero: any other value is treated as infinite.
+#[derive(Component, Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Mass(pub f32);
+
+impl Mass {
+    /// A mass that cannot be moved by any [`Force`] or [`Impulse`]
+    pub const INFINITE: Mass = Mass(f32::INFINITY);
+
+    /// Creates a new mass
+    ///
+    /// # Panics
+    /// `mass` must be greater than zero.
+    #[inline]
+    #[must_use]
+    pub fn new(mass: f32) -> Self {
+        assert!(mass > 0.);
+
+        Mass(mass)
+    }
+
+    /// The reciprocal of this mass, which is zero for an infinite mass
+    ///
+    /// Masses that are not greater than zero are treated as infinite.
+    #[inline]
+    #[must_use]
+    pub fn inverse(self) -> f32 {
+        if self.0 > 0. {
+            self.0.recip()
+        } else {
+            0.
+        }
+    }
+}
+
+impl Default for Mass {
+    fn default() -> Self {
+        Mass(1.)
+    }
+}
+
+/// How strongly an entity resists changes to its [`AngularVelocity`]
+///
+/// This is the rotational equivalent of [`Mass`]:
+/// [`Torque`] and [`AngularImpulse`] are divided by this to get the change in rotation.
+/// Entities without this component are treated as having a moment of inertia of 1.
+///
+/// The moment of inertia should be greater than zero: any other value is treated as infinite.
+#[derive(Component, Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct MomentOfInertia(pub f32);
+
+impl MomentOfInertia {
+    /// A moment of inertia that cannot be rotated by any [`Torque`] or [`AngularImpulse`]
+    pub const INFINITE: MomentOfInertia = MomentOfInertia(f32::INFINITY);
+
+    /// Creates a new moment of inertia
+    ///
+    /// # Panics
+    /// `moment_of_inertia` must be greater than zero.
+    #[inline]
+    #[must_use]
+    pub fn new(moment_of_inertia: f32) -> Self {
+        assert!(moment_of_inertia > 0.);
+
+        MomentOfInertia(moment_of_inertia)
+    }
+
+    /// The reciprocal of this moment of inertia, which is zero for an infinite moment of inertia
+    ///
+    /// Moments of inertia that are not greater than zero are treated as infinite.
+    #[inline]
+    #[must_use]
+    pub fn inverse(self) -> f32 {
+        if self.0 > 0. {
+            self.0.recip()
+        } else {
+            0.
+        }
+    }
+}
+
+impl Default for MomentOfInertia {
+    fn default() -> Self {
+        MomentOfInertia(1.)
+    }
+}
+
+/// A push applied continuously over the kinematics steps of a single frame
+///
+/// Divided by the entity's [`Mass`], this is added to its [`Acceleration`] during every step run this frame,
+/// and then reset to zero by [`clear_forces`](super::systems::clear_forces).
+/// Set this each frame for a steady push, adding to it (rather than overwriting it) to combine forces from several sources.
+///
+/// When used with [`linear_kinematics`](super::systems::linear_kinematics),
+/// the units are [`Mass`] times `C` per second per second.
+#[derive(Component, Default, Clone, Copy, Debug, PartialEq, Add, Sub, AddAssign, SubAssign)]
+pub struct Force<C: Coordinate> {
+    /// Force along the x-axis of a [`Position<C>`](crate::position::Position)
+    pub x: C,
+    /// Force along the y-axis of a [`Position<C>`](crate::position::Position)
+    pub y: C,
+}
+
+/// An instantaneous push, such as a jump or an explosion
+///
+/// Divided by the entity's [`Mass`], this is added to its [`Velocity`] at the start of the next step,
+/// and then reset to zero.
+///
+/// When used with [`linear_kinematics`](super::systems::linear_kinematics),
+/// the units are [`Mass`] times `C` per second.
+#[derive(Component, Default, Clone, Copy, Debug, PartialEq, Add, Sub, AddAssign, SubAssign)]
+pub struct Impulse<C: Coordinate> {
+    /// Impulse along the x-axis of a [`Position<C>`](crate::position::Position)
+    pub x: C,
+    /// Impulse along the y-axis of a [`Position<C>`](crate::position::Position)
+    pub y: C,
+}
+
+/// A twist applied continuously over the kinematics steps of a single frame
+///
+/// Divided by the entity's [`MomentOfInertia`], this is added to its [`AngularAcceleration`] during every step run this frame,
+/// and then reset to zero by [`clear_forces`](super::systems::clear_forces).
+#[derive(Component, Default, Clone, Copy, Debug, PartialEq, Add, Sub, AddAssign, SubAssign)]
+pub struct Torque {
+    /// [`MomentOfInertia`] times tenths of a degree per second per second
+    ///
+    /// Positive is [`Clockwise`](crate::orientation::RotationDirection::Clockwise).
+    /// Negative is [`CounterClockwise`](crate::orientation::RotationDirection::CounterClockwise).
+    pub deci_degrees: f32,
+}
+
+/// An instantaneous twist
+///
+/// Divided by the entity's [`MomentOfInertia`], this is added to its [`AngularVelocity`] at the start of the next step,
+/// and then reset to zero.
+#[derive(Component, Default, Clone, Copy, Debug, PartialEq, Add, Sub, AddAssign, SubAssign)]
+pub struct AngularImpulse {
+    /// [`MomentOfInertia`] times tenths of a degree per second
+    ///
+    /// Positive is [`Clockwise`](crate::orientation::RotationDirection::Clockwise).
+    /// Negative is [`CounterClockwise`](crate::orientation::RotationDirection::CounterClockwise).
+    pub deci_degrees: f32,
+}
+
+impl<C: Coordinate> Div<Mass> for Force<C> {
+    type Output = Acceleration<C>;
+
+    fn div(self, rhs: Mass) -> Acceleration<C> {
+        let scale = C::from(rhs.inverse());
+
+        Acceleration {
+            x: self.x * scale,
+            y: self.y * scale,
+        }
+    }
+}
+
+impl<C: Coordinate> Div<Mass> for Impulse<C> {
+    type Output = Velocity<C>;
+
+    fn div(self, rhs: Mass) -> Velocity<C> {
+        let scale = C::from(rhs.inverse());
+
+        Velocity {
+            x: self.x * scale,
+            y: self.y * scale,
+        }
+    }
+}
+
+impl Div<MomentOfInertia> for Torque {
+    type Output = AngularAcceleration;
+
+    fn div(self, rhs: MomentOfInertia) -> AngularAcceleration {
+        AngularAcceleration {
+            deci_degrees: self.deci_degrees * rhs.inverse(),
+        }
+    }
+}
+
+impl Div<MomentOfInertia> for AngularImpulse {
+    type Output = AngularVelocity;
+
+    fn div(self, rhs: MomentOfInertia) -> AngularVelocity {
+        AngularVelocity {
+            deci_degrees: self.deci_degrees * rhs.inverse(),
+        }
+    }
+}
@@ -0,0 +1,231 @@
+use super::{Acceleration, AngularAcceleration, AngularVelocity, Velocity};
+use crate::coordinate::Coordinate;
+use crate::position::Position;
+use bevy_ecs::component::Component;
+use core::fmt::{Debug, Formatter};
+use core::time::Duration;
+use std::sync::Arc;
+
+/// The numerical scheme used to apply [`Acceleration`] and [`Velocity`] over each step of the kinematics systems
+///
+/// [`TwoDPlugin`](crate::plugin::TwoDPlugin) inserts the integrator for the whole app as a resource,
+/// which can be overridden for individual entities by adding this as a component.
+///
+/// When [`Acceleration`] is constant over a step, [`Integrator::VelocityVerlet`] and [`Integrator::RungeKutta4`] are exact.
+/// They differ from each other (and shine) when an [`AccelerationField`] makes acceleration depend on the entity's state,
+/// such as for orbits or springs.
+///
+/// # Example
+/// ```rust
+/// use core::time::Duration;
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::kinematics::{Acceleration, Integrator, Velocity};
+/// use leafwing_2d::position::Position;
+///
+/// let velocity = Velocity::<F32> { x: F32(1.0), y: F32(0.0) };
+/// let gravity = Acceleration::<F32> { x: F32(0.0), y: F32(-2.0) };
+/// let one_second = Duration::from_secs(1);
+///
+/// // Explicit Euler moves with the old velocity
+/// let (displacement, _) =
+///     Integrator::ExplicitEuler.integrate(Position::default(), velocity, gravity, None, one_second);
+/// assert_eq!(displacement, Position::new(1.0, 0.0));
+///
+/// // Semi-implicit Euler moves with the new velocity
+/// let (displacement, _) =
+///     Integrator::SemiImplicitEuler.integrate(Position::default(), velocity, gravity, None, one_second);
+/// assert_eq!(displacement, Position::new(1.0, -2.0));
+///
+/// // Velocity Verlet follows the exact parabola
+/// let (displacement, new_velocity) =
+///     Integrator::VelocityVerlet.integrate(Position::default(), velocity, gravity, None, one_second);
+/// assert_eq!(displacement, Position::new(1.0, -1.0));
+/// assert_eq!(new_velocity, Velocity { x: F32(1.0), y: F32(-2.0) });
+/// ```
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Integrator {
+    /// Moves by the velocity at the start of the step, then updates the velocity
+    ///
+    /// Simple, but steadily gains energy: orbits spiral outwards.
+    ExplicitEuler,
+    /// Updates the velocity, then moves by the new velocity
+    ///
+    /// As cheap as [`Integrator::ExplicitEuler`], but energy stays bounded over time.
+    /// This is the default.
+    SemiImplicitEuler,
+    /// Moves along the parabola given by the current acceleration,
+    /// then updates the velocity using the average of the old and new acceleration
+    ///
+    /// Second-order accurate, and energy is well-conserved.
+    VelocityVerlet,
+    /// The classic fourth-order Runge-Kutta method
+    ///
+    /// Samples the acceleration four times per step, making it the most accurate and the most expensive.
+    RungeKutta4,
+}
+
+impl Default for Integrator {
+    fn default() -> Self {
+        Integrator::SemiImplicitEuler
+    }
+}
+
+impl Integrator {
+    /// Advances an entity at `position` with `velocity` by `delta_time`
+    ///
+    /// The total acceleration is the constant `acceleration` plus, if provided, the acceleration from the `field`.
+    /// Returns the displacement of the entity over this step, and its new velocity.
+    #[must_use]
+    pub fn integrate<C: Coordinate>(
+        self,
+        position: Position<C>,
+        velocity: Velocity<C>,
+        acceleration: Acceleration<C>,
+        field: Option<&AccelerationField<C>>,
+        delta_time: Duration,
+    ) -> (Position<C>, Velocity<C>) {
+        let acceleration_at = |position: Position<C>, velocity: Velocity<C>| match field {
+            Some(field) => acceleration + field.acceleration_at(position, velocity),
+            None => acceleration,
+        };
+
+        match self {
+            Integrator::ExplicitEuler => {
+                let acceleration = acceleration_at(position, velocity);
+
+                (velocity * delta_time, velocity + acceleration * delta_time)
+            }
+            Integrator::SemiImplicitEuler => {
+                let new_velocity = velocity + acceleration_at(position, velocity) * delta_time;
+
+                (new_velocity * delta_time, new_velocity)
+            }
+            Integrator::VelocityVerlet => {
+                let old_acceleration = acceleration_at(position, velocity);
+                let displacement = (velocity + old_acceleration * delta_time * 0.5) * delta_time;
+
+                // The velocity at the end of the step is estimated for velocity-dependent fields
+                let new_acceleration = acceleration_at(
+                    position + displacement,
+                    velocity + old_acceleration * delta_time,
+                );
+                let new_velocity =
+                    velocity + (old_acceleration + new_acceleration) * delta_time * 0.5;
+
+                (displacement, new_velocity)
+            }
+            Integrator::RungeKutta4 => {
+                let half_time = delta_time / 2;
+
+                let velocity_1 = velocity;
+                let acceleration_1 = acceleration_at(position, velocity_1);
+
+                let velocity_2 = velocity + acceleration_1 * half_time;
+                let acceleration_2 = acceleration_at(position + velocity_1 * half_time, velocity_2);
+
+                let velocity_3 = velocity + acceleration_2 * half_time;
+                let acceleration_3 = acceleration_at(position + velocity_2 * half_time, velocity_3);
+
+                let velocity_4 = velocity + acceleration_3 * delta_time;
+                let acceleration_4 =
+                    acceleration_at(position + velocity_3 * delta_time, velocity_4);
+
+                let sixth_time = delta_time / 6;
+                let displacement =
+                    (velocity_1 + velocity_2 * 2. + velocity_3 * 2. + velocity_4) * sixth_time;
+                let new_velocity = velocity
+                    + (acceleration_1 + acceleration_2 * 2. + acceleration_3 * 2. + acceleration_4)
+                        * sixth_time;
+
+                (displacement, new_velocity)
+            }
+        }
+    }
+
+    /// Advances an entity with `angular_velocity` by `delta_time`
+    ///
+    /// As angular acceleration is always constant over a step,
+    /// [`Integrator::VelocityVerlet`] and [`Integrator::RungeKutta4`] are equivalent here.
+    /// Returns the change in rotation over this step in tenths of a degree, and the new angular velocity.
+    /// The change in rotation is not rounded, so it can be accumulated precisely before converting it into a [`Rotation`](crate::orientation::Rotation).
+    #[must_use]
+    pub fn integrate_angular(
+        self,
+        angular_velocity: AngularVelocity,
+        angular_acceleration: AngularAcceleration,
+        delta_time: Duration,
+    ) -> (f32, AngularVelocity) {
+        let change_in_velocity = angular_acceleration * delta_time;
+        let seconds = delta_time.as_secs_f32();
+
+        match self {
+            Integrator::ExplicitEuler => (
+                angular_velocity.deci_degrees * seconds,
+                angular_velocity + change_in_velocity,
+            ),
+            Integrator::SemiImplicitEuler => {
+                let new_velocity = angular_velocity + change_in_velocity;
+
+                (new_velocity.deci_degrees * seconds, new_velocity)
+            }
+            Integrator::VelocityVerlet | Integrator::RungeKutta4 => (
+                (angular_velocity + change_in_velocity * 0.5).deci_degrees * seconds,
+                angular_velocity + change_in_velocity,
+            ),
+        }
+    }
+}
+
+/// An [`Acceleration`] that depends on the [`Position`] and [`Velocity`] of the entity it is attached to
+///
+/// The kinematics systems add this to the entity's [`Acceleration`] component,
+/// evaluating it as many times per step as its [`Integrator`] requires.
+/// Use this for forces like gravity towards a point or springs, where higher-order integrators
+/// are significantly more accurate than updating the [`Acceleration`] once per frame.
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::kinematics::{Acceleration, AccelerationField};
+/// use leafwing_2d::position::Position;
+///
+/// // A spring pulling the entity towards the origin
+/// let spring = AccelerationField::<F32>::new(|position, _velocity| Acceleration {
+///     x: position.x * F32(-1.0),
+///     y: position.y * F32(-1.0),
+/// });
+///
+/// assert_eq!(
+///     spring.acceleration_at(Position::new(2.0, 0.0), Default::default()),
+///     Acceleration { x: F32(-2.0), y: F32(0.0) }
+/// );
+/// ```
+#[derive(Component, Clone)]
+pub struct AccelerationField<C: Coordinate> {
+    field: Arc<dyn Fn(Position<C>, Velocity<C>) -> Acceleration<C> + Send + Sync>,
+}
+
+impl<C: Coordinate> AccelerationField<C> {
+    /// Creates a new acceleration field from a function of [`Position`] and [`Velocity`]
+    #[must_use]
+    pub fn new(
+        field: impl Fn(Position<C>, Velocity<C>) -> Acceleration<C> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            field: Arc::new(field),
+        }
+    }
+
+    /// The acceleration experienced by an entity at `position` with `velocity`
+    #[inline]
+    #[must_use]
+    pub fn acceleration_at(&self, position: Position<C>, velocity: Velocity<C>) -> Acceleration<C> {
+        (self.field)(position, velocity)
+    }
+}
+
+impl<C: Coordinate> Debug for AccelerationField<C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("AccelerationField").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,163 @@
+use super::{Acceleration, AngularVelocity, Velocity};
+use crate::coordinate::Coordinate;
+use bevy_ecs::component::Component;
+
+/// The fastest that an entity can move, in any direction
+///
+/// The kinematics systems limit both the entity's [`Velocity`] and the distance it travels during each step.
+/// When the velocity is too fast, it is scaled down, so its direction is preserved.
+///
+/// The limit must not be negative.
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::kinematics::{MaxSpeed, Velocity};
+///
+/// let max_speed = MaxSpeed(F32(5.0));
+/// let velocity = Velocity { x: F32(6.0), y: F32(8.0) };
+///
+/// assert_eq!(max_speed.clamp(velocity), Velocity { x: F32(3.0), y: F32(4.0) });
+/// ```
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct MaxSpeed<C: Coordinate>(pub C);
+
+impl<C: Coordinate> MaxSpeed<C> {
+    /// Scales down the `velocity` so that its magnitude does not exceed this limit
+    #[must_use]
+    pub fn clamp(self, velocity: Velocity<C>) -> Velocity<C> {
+        let (x, y) = clamp_magnitude(velocity.x, velocity.y, self.0.into());
+
+        Velocity { x, y }
+    }
+}
+
+/// The fastest that an entity can move along each axis
+///
+/// Unlike [`MaxSpeed`], each component of the [`Velocity`] is limited separately.
+/// This is useful for platformers, where horizontal movement and falling speed are usually limited independently.
+///
+/// The limits must not be negative.
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::kinematics::{MaxSpeedPerAxis, Velocity};
+///
+/// // Run at up to 8 units per second, but fall at up to 20
+/// let max_speed = MaxSpeedPerAxis { x: F32(8.0), y: F32(20.0) };
+/// let velocity = Velocity { x: F32(-10.0), y: F32(-10.0) };
+///
+/// assert_eq!(max_speed.clamp(velocity), Velocity { x: F32(-8.0), y: F32(-10.0) });
+/// ```
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct MaxSpeedPerAxis<C: Coordinate> {
+    /// The largest magnitude of velocity along the x-axis
+    pub x: C,
+    /// The largest magnitude of velocity along the y-axis
+    pub y: C,
+}
+
+impl<C: Coordinate> MaxSpeedPerAxis<C> {
+    /// Limits each component of the `velocity` to the limit for that axis
+    #[must_use]
+    pub fn clamp(self, velocity: Velocity<C>) -> Velocity<C> {
+        Velocity {
+            x: clamp_component(velocity.x, self.x.into()),
+            y: clamp_component(velocity.y, self.y.into()),
+        }
+    }
+}
+
+/// The largest [`Acceleration`] that an entity can experience, in any direction
+///
+/// This limits the total acceleration applied by the kinematics systems,
+/// including that from any [`Force`](super::Force), but not any [`AccelerationField`](super::AccelerationField).
+/// When the acceleration is too strong, it is scaled down, so its direction is preserved.
+///
+/// The limit must not be negative.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct MaxAcceleration<C: Coordinate>(pub C);
+
+impl<C: Coordinate> MaxAcceleration<C> {
+    /// Scales down the `acceleration` so that its magnitude does not exceed this limit
+    #[must_use]
+    pub fn clamp(self, acceleration: Acceleration<C>) -> Acceleration<C> {
+        let (x, y) = clamp_magnitude(acceleration.x, acceleration.y, self.0.into());
+
+        Acceleration { x, y }
+    }
+}
+
+/// The largest [`Acceleration`] that an entity can experience along each axis
+///
+/// Unlike [`MaxAcceleration`], each component is limited separately.
+///
+/// The limits must not be negative.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct MaxAccelerationPerAxis<C: Coordinate> {
+    /// The largest magnitude of acceleration along the x-axis
+    pub x: C,
+    /// The largest magnitude of acceleration along the y-axis
+    pub y: C,
+}
+
+impl<C: Coordinate> MaxAccelerationPerAxis<C> {
+    /// Limits each component of the `acceleration` to the limit for that axis
+    #[must_use]
+    pub fn clamp(self, acceleration: Acceleration<C>) -> Acceleration<C> {
+        Acceleration {
+            x: clamp_component(acceleration.x, self.x.into()),
+            y: clamp_component(acceleration.y, self.y.into()),
+        }
+    }
+}
+
+/// The fastest that an entity can rotate, in either direction
+///
+/// The kinematics systems limit both the entity's [`AngularVelocity`] and how far it rotates during each step.
+///
+/// The limit is measured in tenths of a degree per second, and must not be negative.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct MaxAngularVelocity {
+    /// Tenths of a degree per second
+    pub deci_degrees: f32,
+}
+
+impl MaxAngularVelocity {
+    /// Limits the magnitude of the `angular_velocity`, without changing its direction
+    #[must_use]
+    pub fn clamp(self, angular_velocity: AngularVelocity) -> AngularVelocity {
+        AngularVelocity {
+            deci_degrees: angular_velocity
+                .deci_degrees
+                .max(-self.deci_degrees)
+                .min(self.deci_degrees),
+        }
+    }
+}
+
+/// Scales down the vector `(x, y)` so that its length is at most `max`
+pub(crate) fn clamp_magnitude<C: Coordinate>(x: C, y: C, max: f32) -> (C, C) {
+    let (float_x, float_y): (f32, f32) = (x.into(), y.into());
+    let length = float_x.hypot(float_y);
+
+    if length <= max {
+        (x, y)
+    } else {
+        let scale = max / length;
+
+        (C::from(float_x * scale), C::from(float_y * scale))
+    }
+}
+
+/// Limits `value` to lie between `-max` and `max`
+pub(crate) fn clamp_component<C: Coordinate>(value: C, max: f32) -> C {
+    let float_value: f32 = value.into();
+
+    if float_value.abs() <= max {
+        value
+    } else {
+        C::from(max.copysign(float_value))
+    }
+}
@@ -5,6 +5,7 @@
 
 pub mod bounding;
 pub mod bundles;
+pub mod collision;
 pub mod continuous;
 pub mod coordinate;
 pub mod discrete;
@@ -12,13 +13,22 @@ pub mod errors;
 pub mod kinematics;
 pub mod orientation;
 pub mod partitioning;
+#[cfg(feature = "picking")]
+pub mod picking;
 pub mod plugin;
 pub mod position;
 pub mod scale;
+#[cfg(feature = "camera")]
+pub mod screen;
+pub mod timestep;
+pub mod trigger;
 
 /// The most commonly useful bits of the library
 pub mod prelude {
-    pub use crate::bounding::{AxisAlignedBoundingBox, BoundingRegion};
+    pub use crate::bounding::{
+        AxisAlignedBoundingBox, BoundingCircle, BoundingPolygon, BoundingRegion, Collider, Ray,
+        Raycast,
+    };
     pub use crate::bundles::TwoDBundle;
     pub use crate::continuous::F32;
     pub use crate::coordinate::Coordinate;
@@ -500,6 +500,20 @@ mod rotation {
         pub fn into_degrees(self) -> f32 {
             self.deci_degrees as f32 / 10.
         }
+
+        /// Construct a [`Rotation`] from tenths of a degree, measured clockwise from midnight
+        ///
+        /// The angle is rounded to the nearest tenth of a degree, and may be negative or larger than a full circle.
+        #[must_use]
+        #[inline]
+        pub fn from_deci_degrees(deci_degrees: impl Into<f32>) -> Rotation {
+            let normalized_deci_degrees: f32 = deci_degrees
+                .into()
+                .round()
+                .rem_euclid(Rotation::FULL_CIRCLE as f32);
+
+            Rotation::new(normalized_deci_degrees as u16)
+        }
     }
 
     impl Add for Rotation {
@@ -0,0 +1,306 @@
+//! Finding which entities are under the mouse cursor or a touch, for hovering and clicking
+//!
+//! Requires the `picking` feature.
+
+use crate::bounding::{
+    AxisAlignedBoundingBox, BoundingCircle, BoundingPolygon, BoundingRegion, Collider,
+};
+use crate::continuous::F32;
+use crate::coordinate::Coordinate;
+use crate::plugin::TwoDSystem;
+use crate::position::Position;
+use crate::screen::{screen_to_world, Viewport};
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_input::mouse::MouseButton;
+use bevy_input::touch::Touches;
+use bevy_input::Input;
+use bevy_render::camera::{Camera, RenderTarget};
+use bevy_transform::components::GlobalTransform;
+use bevy_transform::TransformSystem;
+use bevy_window::Windows;
+use core::marker::PhantomData;
+
+/// Sends [`HoverStarted`], [`HoverEnded`] and [`Clicked`] events as the cursor moves over [`Pickable`] entities
+///
+/// Each frame, the cursor or touch position is converted through the [`PickingCamera`] into world space,
+/// and stored in the [`Cursor`] resource along with the entities whose bounding regions are under it.
+/// Overlapping entities are ordered by the z-value of their [`GlobalTransform`], with the topmost entity picked.
+///
+/// For this to work, `stage` must match the stage used by [`TwoDPlugin`](crate::plugin::TwoDPlugin).
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_2d::picking::{Clicked, Pickable, PickingCamera, PickingPlugin};
+/// use leafwing_2d::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     commands
+///         .spawn_bundle(OrthographicCameraBundle::new_2d())
+///         .insert(PickingCamera);
+///
+///     commands
+///         .spawn()
+///         .insert(BoundingCircle::<F32>::new(Position::new(0.0, 0.0), 32.0))
+///         .insert(Pickable);
+/// }
+///
+/// fn report_clicks(mut events: EventReader<Clicked>) {
+///     for event in events.iter() {
+///         println!("Clicked on {:?}", event.0);
+///     }
+/// }
+///
+/// let mut app = App::new();
+/// app.insert_resource(bevy::core::Time::default())
+///     .add_plugin(TwoDPlugin::default())
+///     .add_plugin(PickingPlugin::default())
+///     .add_startup_system(setup)
+///     .add_system(report_clicks);
+///
+/// app.update();
+/// ```
+#[derive(Debug)]
+pub struct PickingPlugin<C: Coordinate, UserStage: StageLabel> {
+    /// Which stage should these systems run in?
+    ///
+    /// Default: [`CoreStage::PostUpdate`]
+    pub stage: UserStage,
+    /// What [`Coordinate`] should be used?
+    ///
+    /// Default: [`F32`]
+    pub coordinate_type: PhantomData<C>,
+}
+
+impl Default for PickingPlugin<F32, CoreStage> {
+    fn default() -> Self {
+        Self {
+            stage: CoreStage::PostUpdate,
+            coordinate_type: PhantomData,
+        }
+    }
+}
+
+impl<C: Coordinate, UserStage: StageLabel + Clone> Plugin for PickingPlugin<C, UserStage> {
+    fn build(&self, app: &mut App) {
+        app.add_event::<HoverStarted>()
+            .add_event::<HoverEnded>()
+            .add_event::<Clicked>()
+            .init_resource::<Cursor<C>>()
+            .add_system_set_to_stage(
+                self.stage.clone(),
+                SystemSet::new()
+                    .label(TwoDSystem::Picking)
+                    .after(TwoDSystem::SyncBounds)
+                    .after(TransformSystem::TransformPropagate)
+                    .with_system(update_cursor::<C>.label(TwoDSystem::UpdateCursor))
+                    .with_system(pick_entities::<C>.after(TwoDSystem::UpdateCursor)),
+            );
+    }
+}
+
+/// Marks the camera used to convert the cursor position into world space
+///
+/// If several cameras are marked, the first one that renders to the window containing the cursor is used.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PickingCamera;
+
+/// Marks an entity as able to be hovered and clicked by the cursor
+///
+/// The entity is under the cursor when any of its [`AxisAlignedBoundingBox`], [`BoundingCircle`], [`BoundingPolygon`] or [`Collider`] components contain it.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Pickable;
+
+/// The world-space position of the cursor, and the [`Pickable`] entities underneath it
+///
+/// The `position` and `just_pressed` fields are set from the mouse or touch input each frame when a window exists,
+/// but can also be set manually, such as to drive a virtual cursor with a gamepad.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cursor<C: Coordinate> {
+    /// The position of the cursor in world space, or `None` if it is outside of every window
+    ///
+    /// Touches take priority over the mouse.
+    pub position: Option<Position<C>>,
+    /// Was the left mouse button pressed or the screen touched this frame?
+    ///
+    /// This is reset by [`pick_entities`] once any click has been sent,
+    /// so a manually driven cursor only needs to set it on the frame of the press.
+    pub just_pressed: bool,
+    under_cursor: Vec<Entity>,
+}
+
+impl<C: Coordinate> Default for Cursor<C> {
+    fn default() -> Self {
+        Self {
+            position: None,
+            just_pressed: false,
+            under_cursor: Vec::new(),
+        }
+    }
+}
+
+impl<C: Coordinate> Cursor<C> {
+    /// The topmost [`Pickable`] entity under the cursor, if any
+    #[inline]
+    #[must_use]
+    pub fn hovered(&self) -> Option<Entity> {
+        self.under_cursor.first().copied()
+    }
+
+    /// Every [`Pickable`] entity under the cursor, from top to bottom
+    #[inline]
+    #[must_use]
+    pub fn under_cursor(&self) -> &[Entity] {
+        &self.under_cursor
+    }
+}
+
+/// Sent when the cursor starts hovering over an entity
+///
+/// Only the topmost entity under the cursor is hovered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HoverStarted(pub Entity);
+
+/// Sent when the cursor stops hovering over an entity, or it is despawned while hovered
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HoverEnded(pub Entity);
+
+/// Sent when the left mouse button is pressed or the screen is touched while hovering over an entity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Clicked(pub Entity);
+
+/// Sets the [`Cursor`] position and press state from the mouse and touch input
+///
+/// Does nothing if there is no [`Windows`] resource, so the [`Cursor`] can be controlled manually.
+pub fn update_cursor<C: Coordinate>(
+    mut cursor: ResMut<Cursor<C>>,
+    windows: Option<Res<Windows>>,
+    mouse_buttons: Option<Res<Input<MouseButton>>>,
+    touches: Option<Res<Touches>>,
+    cameras: Query<(&Camera, &GlobalTransform), With<PickingCamera>>,
+) {
+    let windows = match windows {
+        Some(windows) => windows,
+        None => return,
+    };
+
+    let touch = touches.as_ref().and_then(|touches| touches.iter().next());
+    let just_touched = touches
+        .as_ref()
+        .filter(|touches| touches.iter_just_pressed().next().is_some())
+        .is_some();
+    let just_clicked = mouse_buttons
+        .as_ref()
+        .filter(|mouse_buttons| mouse_buttons.just_pressed(MouseButton::Left))
+        .is_some();
+
+    let position = cameras.iter().find_map(|(camera, camera_transform)| {
+        let window = match &camera.target {
+            RenderTarget::Window(window_id) => windows.get(*window_id)?,
+            RenderTarget::Image(_) => return None,
+        };
+        let screen_position = match touch {
+            Some(touch) => touch.position(),
+            None => window.cursor_position()?,
+        };
+
+        screen_to_world(
+            screen_position,
+            &Viewport::from_window(window)?,
+            camera,
+            camera_transform,
+        )
+    });
+
+    let just_pressed = just_touched || just_clicked;
+
+    // Avoid triggering change detection needlessly
+    if cursor.position != position || cursor.just_pressed != just_pressed {
+        cursor.position = position;
+        cursor.just_pressed = just_pressed;
+    }
+}
+
+/// Finds the [`Pickable`] entities under the [`Cursor`], sending hover and click events
+///
+/// Resets [`Cursor::just_pressed`] afterwards, so that each press is only handled once.
+pub fn pick_entities<C: Coordinate>(
+    mut cursor: ResMut<Cursor<C>>,
+    query: Query<
+        (
+            Entity,
+            Option<&AxisAlignedBoundingBox<C>>,
+            Option<&BoundingCircle<C>>,
+            Option<&BoundingPolygon<C>>,
+            Option<&Collider<C>>,
+            Option<&GlobalTransform>,
+        ),
+        With<Pickable>,
+    >,
+    mut hover_started: EventWriter<HoverStarted>,
+    mut hover_ended: EventWriter<HoverEnded>,
+    mut clicked: EventWriter<Clicked>,
+) {
+    let mut under_cursor: Vec<(Entity, f32)> = match cursor.position {
+        Some(position) => query
+            .iter()
+            .filter(
+                |&(_, maybe_aabb, maybe_circle, maybe_polygon, maybe_collider, _)| {
+                    maybe_aabb.filter(|aabb| aabb.contains(position)).is_some()
+                        || maybe_circle
+                            .filter(|circle| circle.contains(position))
+                            .is_some()
+                        || maybe_polygon
+                            .filter(|polygon| polygon.contains(position))
+                            .is_some()
+                        || maybe_collider
+                            .filter(|collider| collider.contains(position))
+                            .is_some()
+                },
+            )
+            .map(|(entity, .., maybe_transform)| {
+                (
+                    entity,
+                    maybe_transform.map_or(0., |transform| transform.translation.z),
+                )
+            })
+            .collect(),
+        None => Vec::new(),
+    };
+
+    // Topmost first, with later entities drawn on top of earlier ones in case of ties
+    under_cursor.sort_by(|(a_entity, a_z), (b_entity, b_z)| {
+        b_z.partial_cmp(a_z)
+            .unwrap_or(core::cmp::Ordering::Equal)
+            .then(b_entity.cmp(a_entity))
+    });
+    let under_cursor: Vec<Entity> = under_cursor.into_iter().map(|(entity, _)| entity).collect();
+
+    let previously_hovered = cursor.hovered();
+    let hovered = under_cursor.first().copied();
+
+    if previously_hovered != hovered {
+        if let Some(entity) = previously_hovered {
+            hover_ended.send(HoverEnded(entity));
+        }
+
+        if let Some(entity) = hovered {
+            hover_started.send(HoverStarted(entity));
+        }
+    }
+
+    if cursor.just_pressed {
+        if let Some(entity) = hovered {
+            clicked.send(Clicked(entity));
+        }
+
+        cursor.just_pressed = false;
+    }
+
+    // Avoid triggering change detection needlessly
+    if cursor.under_cursor != under_cursor {
+        cursor.under_cursor = under_cursor;
+    }
+}
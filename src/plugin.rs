@@ -1,20 +1,40 @@
 //! Tools for using two-dimensional coordinates within `bevy` games
 
+#[cfg(feature = "sprite")]
+use crate::bounding::BoundsFromSprite;
+use crate::bounding::{
+    combined_bounds, AxisAlignedBoundingBox, BoundingCircle, BoundingPolygon,
+    BoundingVolumeHierarchy, Collider, CollisionLayers, LocalBoundingBox, Quadtree, SpatialHash,
+};
 use crate::continuous::F32;
 use crate::coordinate::Coordinate;
-use crate::kinematics::systems::{angular_kinematics, linear_kinematics};
+use crate::kinematics::systems::{
+    angular_kinematics, clear_forces, collision_response, continuous_linear_kinematics,
+    linear_kinematics,
+};
+use crate::kinematics::Integrator;
 use crate::orientation::{Direction, Rotation};
 use crate::position::Position;
+use crate::scale::Scale;
+use crate::timestep::{interpolate_transforms, record_previous_state, FixedTimestep, Interpolated};
 
 use bevy_app::prelude::*;
+#[cfg(feature = "sprite")]
+use bevy_asset::{Assets, Handle};
+use bevy_core::Time;
 use bevy_ecs::prelude::*;
 use bevy_ecs::schedule::ShouldRun;
-use bevy_ecs::system::Resource;
+use bevy_ecs::system::{Resource, SystemParam};
 use bevy_math::Quat;
+#[cfg(feature = "sprite")]
+use bevy_render::texture::Image;
+#[cfg(feature = "sprite")]
+use bevy_sprite::Sprite;
 use bevy_transform::components::Transform;
 use core::fmt::Debug;
 use core::hash::Hash;
 use core::marker::PhantomData;
+use core::time::Duration;
 
 /// Ensures that two-dimensional [`Position`], [`Direction`] and [`Rotation`] components are synchronized with the [`Transform`] equivalent
 ///
@@ -32,6 +52,7 @@ use core::marker::PhantomData;
 /// use leafwing_2d::prelude::*;
 /// use leafwing_2d::plugin::GameState;
 /// use leafwing_2d::discrete::FlatHex;
+/// use leafwing_2d::kinematics::Integrator;
 /// use core::marker::PhantomData;
 ///
 /// // This is a sensible starting point for a grid-based game
@@ -40,6 +61,8 @@ use core::marker::PhantomData;
 ///     .add_plugin(TwoDPlugin {
 ///       kinematics: false,
 ///       kinematics_state: None,
+///       fixed_timestep: None,
+///       integrator: Integrator::SemiImplicitEuler,
 ///       stage: CoreStage::PostUpdate,
 ///       // Hexagons are the bestagons
 ///       coordinate_type: PhantomData::<FlatHex>::default(),
@@ -63,6 +86,20 @@ pub struct TwoDPlugin<
     ///
     /// Default: [`None`]
     pub kinematics_state: Option<UserState>,
+    /// If set, kinematics are run in steps of this length, rather than once per frame
+    ///
+    /// This inserts a [`FixedTimestep`] resource.
+    /// Add the [`Interpolated`] component to entities to render them smoothly between steps.
+    ///
+    /// Default: [`None`]
+    pub fixed_timestep: Option<Duration>,
+    /// How should acceleration and velocity be integrated?
+    ///
+    /// This is inserted as a resource, and can be overridden for individual entities
+    /// by adding an [`Integrator`] component to them.
+    ///
+    /// Default: [`Integrator::SemiImplicitEuler`]
+    pub integrator: Integrator,
     /// Which stage should these systems run in?
     ///
     /// Default: [`CoreStage::PostUpdate`]
@@ -78,6 +115,8 @@ impl Default for TwoDPlugin<F32, GameState, CoreStage> {
         Self {
             kinematics: true,
             kinematics_state: None,
+            fixed_timestep: None,
+            integrator: Integrator::SemiImplicitEuler,
             stage: CoreStage::PostUpdate,
             coordinate_type: PhantomData::<F32>::default(),
         }
@@ -98,16 +137,37 @@ pub enum GameState {
 /// These labels are executed in sequence.
 #[derive(SystemLabel, Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum TwoDSystem {
+    /// Records the previous state of [`Interpolated`] entities before kinematics are applied
+    ///
+    /// Runs once per step when a [`FixedTimestep`] is used, and is disabled along with [`TwoDSystem::Kinematics`].
+    ///
+    /// Contains [`record_previous_state::<C>`].
+    RecordPreviousState,
     /// Applies acceleration and velocity
     ///
-    /// Contains [`linear_kinematics::<C>`] and [`angular_kinematics`].
+    /// Contains [`linear_kinematics::<C>`], [`continuous_linear_kinematics::<C>`] and [`angular_kinematics`].
     /// Disable these by setting the `kinematics` field of [`TwoDPlugin`].
+    /// If the `fixed_timestep` field of [`TwoDPlugin`] is set, these run zero or more times per frame.
     Kinematics,
+    /// Pushes entities with [`CollisionResponse`](crate::bounding::CollisionResponse) out of static obstacles
+    ///
+    /// Runs after [`TwoDSystem::Kinematics`], and is disabled along with it.
+    ///
+    /// Contains [`collision_response::<C>`].
+    CollisionResponse,
+    /// Resets every [`Force`](crate::kinematics::Force) and [`Torque`](crate::kinematics::Torque) to zero
+    ///
+    /// Runs once per frame, after every step of kinematics, and is disabled along with [`TwoDSystem::Kinematics`].
+    ///
+    /// Contains [`clear_forces::<C>`].
+    ClearForces,
     /// Synchronizes the [`Direction`] and [`Rotation`] of all entities
     ///
     /// If [`Direction`] and [`Rotation`] are desynced, whichever one was changed will be used and the other will be made consistent.
     /// If both were changed, [`Rotation`] will be prioritized
     ///
+    /// Like the rest of the synchronization systems, this runs once per frame, after every step of kinematics.
+    ///
     /// Contains [`sync_direction_and_rotation`].
     SyncDirectionRotation,
     /// Synchronizes the [`Rotation`] and [`Position`] of each entity with its [`Transform`]
@@ -116,6 +176,58 @@ pub enum TwoDSystem {
     ///
     /// Contains [`sync_transform_with_2d`].
     SyncTransform,
+    /// Sets the [`Transform`] of [`Interpolated`] entities between their previous and current state
+    ///
+    /// Contains [`interpolate_transforms::<C>`].
+    InterpolateTransform,
+    /// Recomputes the world-space [`AxisAlignedBoundingBox`] of entities with a [`LocalBoundingBox`]
+    ///
+    /// This accounts for the entity's [`Position`], [`Rotation`] and [`Scale`].
+    /// Along with the spatial index updates, this runs after every step of kinematics, and then once more at the end of the frame.
+    ///
+    /// Contains [`sync_bounds_with_2d`].
+    SyncBounds,
+    /// Sets the [`LocalBoundingBox`] of entities with [`BoundsFromSprite`](crate::bounding::BoundsFromSprite) to match their sprite
+    ///
+    /// Contains `sync_bounds_with_sprite`; requires the `sprite` feature.
+    SyncSpriteBounds,
+    /// Updates the [`SpatialHash`] resource, if it exists, to match each entity's bounding components
+    ///
+    /// Contains [`update_spatial_hash`].
+    UpdateSpatialHash,
+    /// Updates the [`Quadtree`] resource, if it exists, to match each entity's bounding components
+    ///
+    /// Contains [`update_quadtree`].
+    UpdateQuadtree,
+    /// Refits the [`BoundingVolumeHierarchy`] resource, if it exists, around entities that have moved
+    ///
+    /// Contains [`refit_bounding_volume_hierarchy`].
+    RefitBoundingVolumeHierarchy,
+    /// Detects overlapping entities and sends collision events
+    ///
+    /// Only used when [`CollisionPlugin`](crate::collision::CollisionPlugin) is added.
+    ///
+    /// Contains [`detect_collisions`](crate::collision::detect_collisions).
+    Collisions,
+    /// Updates the occupants of each [`TriggerZone`](crate::trigger::TriggerZone) and sends trigger events
+    ///
+    /// Only used when [`CollisionPlugin`](crate::collision::CollisionPlugin) is added.
+    ///
+    /// Contains [`update_trigger_zones`](crate::trigger::update_trigger_zones) for the built-in bounding regions.
+    TriggerZones,
+    /// Sets the world-space position of the [`Cursor`](crate::picking::Cursor) from mouse and touch input
+    ///
+    /// Only used when [`PickingPlugin`](crate::picking::PickingPlugin) is added.
+    ///
+    /// Contains `update_cursor`; requires the `picking` feature.
+    UpdateCursor,
+    /// Every picking system: updates the cursor, then finds the entities under it and sends hover and click events
+    ///
+    /// Only used when [`PickingPlugin`](crate::picking::PickingPlugin) is added.
+    /// Use [`TwoDSystem::UpdateCursor`] to run systems between these steps.
+    ///
+    /// Contains the whole picking system set, `update_cursor` followed by `pick_entities`; requires the `picking` feature.
+    Picking,
 }
 
 impl<
@@ -126,11 +238,37 @@ impl<
 {
     fn build(&self, app: &mut App) {
         if self.kinematics {
+            // These systems share a single run criteria, so that each fixed timestep runs all of them in order
             let kinematics_systems = SystemSet::new()
-                .with_system(linear_kinematics::<C>)
-                .with_system(angular_kinematics)
-                .label(TwoDSystem::Kinematics)
-                .before(TwoDSystem::SyncDirectionRotation);
+                .with_system(record_previous_state::<C>.label(TwoDSystem::RecordPreviousState))
+                .with_system(
+                    linear_kinematics::<C>
+                        .label(TwoDSystem::Kinematics)
+                        .after(TwoDSystem::RecordPreviousState),
+                )
+                .with_system(
+                    continuous_linear_kinematics::<C>
+                        .label(TwoDSystem::Kinematics)
+                        .after(TwoDSystem::RecordPreviousState),
+                )
+                .with_system(
+                    angular_kinematics
+                        .label(TwoDSystem::Kinematics)
+                        .after(TwoDSystem::RecordPreviousState),
+                )
+                .with_system(
+                    collision_response::<C>
+                        .label(TwoDSystem::CollisionResponse)
+                        .after(TwoDSystem::Kinematics),
+                )
+                .before(TwoDSystem::SyncDirectionRotation)
+                .before(TwoDSystem::SyncTransform);
+
+            app.insert_resource(self.integrator);
+
+            if let Some(step) = self.fixed_timestep {
+                app.insert_resource(FixedTimestep::new(step));
+            }
 
             // If a state has been provided
             // Only run this plugin's systems in the state variant provided
@@ -140,28 +278,117 @@ impl<
                 // https://github.com/bevyengine/rfcs/pull/45 will make special-casing state support unnecessary
 
                 // Captured the state variant we want our systems to run in in a run-criteria closure
-                // The `SystemSet` methods take self by ownership, so we must store a new system set
-                let kinematics_systems = kinematics_systems.with_run_criteria(
-                    move |current_state: Res<State<UserState>>| {
+                let in_desired_state =
+                    move |current_state: Res<State<UserState>>,
+                          time: Res<Time>,
+                          fixed_timestep: Option<ResMut<FixedTimestep>>| {
                         if *current_state.current() == desired_state_variant {
-                            ShouldRun::Yes
+                            run_kinematics_step(&time, fixed_timestep)
                         } else {
                             ShouldRun::No
                         }
-                    },
-                );
+                    };
 
-                app.add_system_set_to_stage(self.stage.clone(), kinematics_systems);
+                app.add_system_set_to_stage(
+                    self.stage.clone(),
+                    kinematics_systems.with_run_criteria(in_desired_state),
+                );
             } else {
-                app.add_system_set_to_stage(self.stage.clone(), kinematics_systems);
+                app.add_system_set_to_stage(
+                    self.stage.clone(),
+                    kinematics_systems.with_run_criteria(
+                        |time: Res<Time>, fixed_timestep: Option<ResMut<FixedTimestep>>| {
+                            run_kinematics_step(&time, fixed_timestep)
+                        },
+                    ),
+                );
             }
+
+            // Run criteria are evaluated in the order they were added,
+            // so this sees whether the kinematics systems will run again this frame
+            app.add_system_set_to_stage(
+                self.stage.clone(),
+                SystemSet::new()
+                    .with_run_criteria(after_kinematics_steps)
+                    .with_system(
+                        clear_forces::<C>
+                            .label(TwoDSystem::ClearForces)
+                            .after(TwoDSystem::CollisionResponse),
+                    ),
+            );
         }
 
+        // Like `clear_forces`, these only run once the final step of the frame is complete
         let sync_systems = SystemSet::new()
+            .with_run_criteria(after_kinematics_steps)
             .with_system(sync_direction_and_rotation.label(TwoDSystem::SyncDirectionRotation))
-            .with_system(sync_transform_with_2d::<C>.label(TwoDSystem::SyncTransform));
+            .with_system(sync_transform_with_2d::<C>.label(TwoDSystem::SyncTransform))
+            .with_system(
+                interpolate_transforms::<C>
+                    .label(TwoDSystem::InterpolateTransform)
+                    .after(TwoDSystem::SyncDirectionRotation)
+                    .after(TwoDSystem::SyncTransform),
+            );
 
-        app.add_system_set_to_stage(self.stage.clone(), sync_systems);
+        // These run after every step, so that each step collides against up-to-date bounds,
+        // and then once more to pick up any changes made outside of kinematics
+        let bounds_systems = SystemSet::new()
+            .with_run_criteria(after_each_kinematics_step)
+            .with_system(
+                sync_bounds_with_2d::<C>
+                    .label(TwoDSystem::SyncBounds)
+                    .after(TwoDSystem::SyncDirectionRotation)
+                    .after(TwoDSystem::SyncTransform),
+            )
+            .with_system(
+                update_spatial_hash::<C>
+                    .label(TwoDSystem::UpdateSpatialHash)
+                    .after(TwoDSystem::SyncBounds),
+            )
+            .with_system(
+                update_quadtree::<C>
+                    .label(TwoDSystem::UpdateQuadtree)
+                    .after(TwoDSystem::SyncBounds),
+            )
+            .with_system(
+                refit_bounding_volume_hierarchy::<C>
+                    .label(TwoDSystem::RefitBoundingVolumeHierarchy)
+                    .after(TwoDSystem::SyncBounds),
+            );
+
+        #[cfg(feature = "sprite")]
+        let bounds_systems = bounds_systems.with_system(
+            sync_bounds_with_sprite::<C>
+                .label(TwoDSystem::SyncSpriteBounds)
+                .before(TwoDSystem::SyncBounds),
+        );
+
+        app.add_system_set_to_stage(self.stage.clone(), sync_systems)
+            .add_system_set_to_stage(self.stage.clone(), bounds_systems);
+    }
+}
+
+/// Runs kinematics once per frame, or once per step of the [`FixedTimestep`] if it exists
+fn run_kinematics_step(time: &Time, fixed_timestep: Option<ResMut<FixedTimestep>>) -> ShouldRun {
+    match fixed_timestep {
+        Some(mut fixed_timestep) => fixed_timestep.advance(time.delta()),
+        None => ShouldRun::Yes,
+    }
+}
+
+/// Runs once per frame, after every step of the [`FixedTimestep`] (if any) has run
+fn after_kinematics_steps(fixed_timestep: Option<Res<FixedTimestep>>) -> ShouldRun {
+    match fixed_timestep {
+        Some(fixed_timestep) if fixed_timestep.is_stepping() => ShouldRun::NoAndCheckAgain,
+        _ => ShouldRun::Yes,
+    }
+}
+
+/// Runs after each step of the [`FixedTimestep`] (if any), and once more when the steps for this frame are complete
+fn after_each_kinematics_step(fixed_timestep: Option<Res<FixedTimestep>>) -> ShouldRun {
+    match fixed_timestep {
+        Some(fixed_timestep) if fixed_timestep.is_stepping() => ShouldRun::YesAndCheckAgain,
+        _ => ShouldRun::Yes,
     }
 }
 
@@ -194,6 +421,8 @@ pub fn sync_direction_and_rotation(mut query: Query<(&mut Direction, &mut Rotati
 ///
 /// z-values of the [`Transform`] translation will not be modified.
 /// Any off-axis rotation of the [`Transform`]'s rotation quaternion will be lost.
+///
+/// [`Interpolated`] entities are skipped: their [`Transform`] is set by [`interpolate_transforms`] instead.
 // FIXME: also sync `Scale`.
 pub fn sync_transform_with_2d<C: Coordinate>(
     mut query: Query<
@@ -203,7 +432,10 @@ pub fn sync_transform_with_2d<C: Coordinate>(
             Option<&mut Direction>,
             Option<&mut Position<C>>,
         ),
-        Or<(With<Rotation>, With<Position<C>>)>,
+        (
+            Or<(With<Rotation>, With<Position<C>>)>,
+            Without<Interpolated<C>>,
+        ),
     >,
 ) {
     for (mut transform, maybe_rotation, maybe_direction, maybe_position) in query.iter_mut() {
@@ -261,3 +493,291 @@ pub fn sync_transform_with_2d<C: Coordinate>(
         }
     }
 }
+
+/// Recomputes the world-space [`AxisAlignedBoundingBox`] of each entity with a [`LocalBoundingBox`]
+///
+/// The [`Rotation`] and [`Scale`] components are optional.
+pub fn sync_bounds_with_2d<C: Coordinate>(
+    mut query: Query<(
+        &mut AxisAlignedBoundingBox<C>,
+        &LocalBoundingBox<C>,
+        &Position<C>,
+        Option<&Rotation>,
+        Option<&Scale<C>>,
+    )>,
+) {
+    for (mut aabb, local_bounds, &position, maybe_rotation, maybe_scale) in query.iter_mut() {
+        let new_aabb = local_bounds.world_bounds(position, maybe_rotation, maybe_scale);
+
+        // Avoid triggering change detection needlessly
+        if *aabb != new_aabb {
+            *aabb = new_aabb;
+        }
+    }
+}
+
+/// Sets the [`LocalBoundingBox`] of each entity with [`BoundsFromSprite`] to match the size of its sprite
+///
+/// The sprite's `custom_size` takes priority; otherwise the texture's size is used once it has loaded.
+#[cfg(feature = "sprite")]
+pub fn sync_bounds_with_sprite<C: Coordinate>(
+    images: Option<Res<Assets<Image>>>,
+    mut query: Query<
+        (&mut LocalBoundingBox<C>, &Sprite, Option<&Handle<Image>>),
+        With<BoundsFromSprite>,
+    >,
+) {
+    for (mut local_bounds, sprite, maybe_handle) in query.iter_mut() {
+        let maybe_size = sprite.custom_size.or_else(|| {
+            let image = images.as_ref()?.get(maybe_handle?)?;
+            Some(image.size())
+        });
+
+        if let Some(size) = maybe_size {
+            let new_bounds = LocalBoundingBox(AxisAlignedBoundingBox::from_sprite_size(
+                Position::default(),
+                size,
+            ));
+
+            // Avoid triggering change detection needlessly
+            if *local_bounds != new_bounds {
+                *local_bounds = new_bounds;
+            }
+        }
+    }
+}
+
+/// Updates the [`SpatialHash`] resource to match the bounding components and [`CollisionLayers`] of each entity
+///
+/// Entities with several bounding components are stored with the union of their bounds.
+/// Only entities whose [`Position`], bounding components or [`CollisionLayers`] have changed are moved.
+/// Does nothing unless a [`SpatialHash`] resource has been inserted.
+pub fn update_spatial_hash<C: Coordinate>(
+    spatial_hash: Option<ResMut<SpatialHash<C>>>,
+    changes: BoundsChanges<C>,
+) {
+    if let Some(spatial_hash) = spatial_hash {
+        update_spatial_index(spatial_hash, &changes);
+    }
+}
+
+/// Updates the [`Quadtree`] resource to match the bounding components and [`CollisionLayers`] of each entity
+///
+/// Entities with several bounding components are stored with the union of their bounds.
+/// Only entities whose [`Position`], bounding components or [`CollisionLayers`] have changed are moved.
+/// Does nothing unless a [`Quadtree`] resource has been inserted.
+pub fn update_quadtree<C: Coordinate>(
+    quadtree: Option<ResMut<Quadtree<C>>>,
+    changes: BoundsChanges<C>,
+) {
+    if let Some(quadtree) = quadtree {
+        update_spatial_index(quadtree, &changes);
+    }
+}
+
+/// The bounding components of each entity, and which entities' bounds have changed since the last run
+#[derive(SystemParam)]
+pub struct BoundsChanges<'w, 's, C: Coordinate> {
+    changed_query: Query<
+        'w,
+        's,
+        Entity,
+        Or<(
+            Changed<Position<C>>,
+            Changed<AxisAlignedBoundingBox<C>>,
+            Changed<BoundingCircle<C>>,
+            Changed<BoundingPolygon<C>>,
+            Changed<Collider<C>>,
+            Changed<CollisionLayers>,
+        )>,
+    >,
+    bounds_query: Query<
+        'w,
+        's,
+        (
+            Entity,
+            Option<&'static AxisAlignedBoundingBox<C>>,
+            Option<&'static BoundingCircle<C>>,
+            Option<&'static BoundingPolygon<C>>,
+            Option<&'static Collider<C>>,
+            Option<&'static CollisionLayers>,
+        ),
+    >,
+    removed_aabbs: RemovedComponents<'w, AxisAlignedBoundingBox<C>>,
+    removed_circles: RemovedComponents<'w, BoundingCircle<C>>,
+    removed_polygons: RemovedComponents<'w, BoundingPolygon<C>>,
+    removed_colliders: RemovedComponents<'w, Collider<C>>,
+    removed_layers: RemovedComponents<'w, CollisionLayers>,
+}
+
+/// A spatial index that stores the bounds and [`CollisionLayers`] of entities
+trait SpatialIndex<C: Coordinate> {
+    fn get(&self, entity: Entity) -> Option<&AxisAlignedBoundingBox<C>>;
+
+    fn insert(&mut self, entity: Entity, aabb: AxisAlignedBoundingBox<C>);
+
+    fn remove(&mut self, entity: Entity);
+
+    fn layers(&self, entity: Entity) -> CollisionLayers;
+
+    fn set_layers(&mut self, entity: Entity, layers: CollisionLayers) -> bool;
+}
+
+impl<C: Coordinate> SpatialIndex<C> for SpatialHash<C> {
+    fn get(&self, entity: Entity) -> Option<&AxisAlignedBoundingBox<C>> {
+        SpatialHash::get(self, entity)
+    }
+
+    fn insert(&mut self, entity: Entity, aabb: AxisAlignedBoundingBox<C>) {
+        SpatialHash::insert(self, entity, aabb);
+    }
+
+    fn remove(&mut self, entity: Entity) {
+        SpatialHash::remove(self, entity);
+    }
+
+    fn layers(&self, entity: Entity) -> CollisionLayers {
+        SpatialHash::layers(self, entity)
+    }
+
+    fn set_layers(&mut self, entity: Entity, layers: CollisionLayers) -> bool {
+        SpatialHash::set_layers(self, entity, layers)
+    }
+}
+
+impl<C: Coordinate> SpatialIndex<C> for Quadtree<C> {
+    fn get(&self, entity: Entity) -> Option<&AxisAlignedBoundingBox<C>> {
+        Quadtree::get(self, entity)
+    }
+
+    fn insert(&mut self, entity: Entity, aabb: AxisAlignedBoundingBox<C>) {
+        Quadtree::insert(self, entity, aabb);
+    }
+
+    fn remove(&mut self, entity: Entity) {
+        Quadtree::remove(self, entity);
+    }
+
+    fn layers(&self, entity: Entity) -> CollisionLayers {
+        Quadtree::layers(self, entity)
+    }
+
+    fn set_layers(&mut self, entity: Entity, layers: CollisionLayers) -> bool {
+        Quadtree::set_layers(self, entity, layers)
+    }
+}
+
+/// Stores the combined bounds and [`CollisionLayers`] of each changed entity in the `index`
+fn update_spatial_index<C: Coordinate, I: SpatialIndex<C> + Resource>(
+    mut index: ResMut<I>,
+    changes: &BoundsChanges<C>,
+) {
+    // Freshly inserted resources must be filled from scratch
+    let entities: Vec<Entity> = if index.is_added() {
+        changes
+            .bounds_query
+            .iter()
+            .map(|(entity, ..)| entity)
+            .collect()
+    } else {
+        changes
+            .changed_query
+            .iter()
+            .chain(changes.removed_aabbs.iter())
+            .chain(changes.removed_circles.iter())
+            .chain(changes.removed_polygons.iter())
+            .chain(changes.removed_colliders.iter())
+            .chain(changes.removed_layers.iter())
+            .collect()
+    };
+
+    for entity in entities {
+        let (bounds, layers) = match changes.bounds_query.get(entity) {
+            Ok((_, maybe_aabb, maybe_circle, maybe_polygon, maybe_collider, maybe_layers)) => (
+                combined_bounds(maybe_aabb, maybe_circle, maybe_polygon, maybe_collider),
+                maybe_layers.copied().unwrap_or_default(),
+            ),
+            Err(_) => (None, CollisionLayers::default()),
+        };
+
+        match bounds {
+            Some(aabb) => {
+                if index.get(entity) != Some(&aabb) {
+                    index.insert(entity, aabb);
+                }
+                if index.layers(entity) != layers {
+                    index.set_layers(entity, layers);
+                }
+            }
+            None => {
+                index.remove(entity);
+            }
+        }
+    }
+}
+
+/// Refits the [`BoundingVolumeHierarchy`] resource around entities whose [`Position`] or bounding components have changed
+///
+/// Entities with several bounding components are stored with the union of their bounds.
+/// The [`CollisionLayers`] of each entity are kept up to date too.
+/// Entities that were not part of the hierarchy when it was built are ignored.
+/// Does nothing unless a [`BoundingVolumeHierarchy`] resource has been inserted.
+pub fn refit_bounding_volume_hierarchy<C: Coordinate>(
+    bvh: Option<ResMut<BoundingVolumeHierarchy<C>>>,
+    changed_query: Query<
+        Entity,
+        Or<(
+            Changed<Position<C>>,
+            Changed<AxisAlignedBoundingBox<C>>,
+            Changed<BoundingCircle<C>>,
+            Changed<BoundingPolygon<C>>,
+            Changed<Collider<C>>,
+            Changed<CollisionLayers>,
+        )>,
+    >,
+    bounds_query: Query<(
+        Option<&AxisAlignedBoundingBox<C>>,
+        Option<&BoundingCircle<C>>,
+        Option<&BoundingPolygon<C>>,
+        Option<&Collider<C>>,
+        Option<&CollisionLayers>,
+    )>,
+    removed_layers: RemovedComponents<CollisionLayers>,
+) {
+    let mut bvh = match bvh {
+        Some(bvh) => bvh,
+        None => return,
+    };
+
+    // Freshly inserted resources may have been built without layers
+    let entities: Vec<Entity> = if bvh.is_added() {
+        bvh.entities().collect()
+    } else {
+        changed_query.iter().chain(removed_layers.iter()).collect()
+    };
+
+    for entity in entities {
+        // Only the entities that the hierarchy was built from are tracked
+        if bvh.get(entity).is_none() {
+            continue;
+        }
+
+        let (maybe_aabb, maybe_circle, maybe_polygon, maybe_collider, maybe_layers) =
+            match bounds_query.get(entity) {
+                Ok(components) => components,
+                Err(_) => continue,
+            };
+
+        if let Some(aabb) = combined_bounds(maybe_aabb, maybe_circle, maybe_polygon, maybe_collider)
+        {
+            if bvh.get(entity) != Some(&aabb) {
+                bvh.refit(entity, aabb);
+            }
+        }
+
+        let layers = maybe_layers.copied().unwrap_or_default();
+        if bvh.layers(entity) != layers {
+            bvh.set_layers(entity, layers);
+        }
+    }
+}
@@ -56,6 +56,22 @@ mod position_struct {
                 y: y.into(),
             }
         }
+
+        /// Converts a point in screen space into a world-space [`Position`], as seen through the `camera`
+        ///
+        /// Returns `None` if the point lies outside of the `viewport`.
+        /// See [`screen_to_world`](crate::screen::screen_to_world) for details; requires the `camera` feature.
+        #[cfg(feature = "camera")]
+        #[inline]
+        #[must_use]
+        pub fn from_screen_space(
+            screen_position: bevy_math::Vec2,
+            viewport: &crate::screen::Viewport,
+            camera: &bevy_render::camera::Camera,
+            camera_transform: &bevy_transform::components::GlobalTransform,
+        ) -> Option<Position<C>> {
+            crate::screen::screen_to_world(screen_position, viewport, camera, camera_transform)
+        }
     }
 
     impl<C: Coordinate> Position<C> {
@@ -130,6 +146,21 @@ mod positionlike {
                 "{self:?} (converted to {self_vec2}) was {distance} away from {other:?} (converted to {other_vec2})."
             );
         }
+
+        /// Converts this world-space position into screen space, as seen through the `camera`
+        ///
+        /// See [`world_to_screen`](crate::screen::world_to_screen) for details; requires the `camera` feature.
+        #[cfg(feature = "camera")]
+        #[inline]
+        #[must_use]
+        fn to_screen_space(
+            self,
+            viewport: &crate::screen::Viewport,
+            camera: &bevy_render::camera::Camera,
+            camera_transform: &GlobalTransform,
+        ) -> Vec2 {
+            crate::screen::world_to_screen(self, viewport, camera, camera_transform)
+        }
     }
 
     impl<C: Coordinate> Positionlike for Position<C> {
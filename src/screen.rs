@@ -0,0 +1,126 @@
+//! Converting between screen space and world space through a camera
+//!
+//! Screen space is measured in logical pixels from the bottom-left corner of the window,
+//! matching the cursor positions reported by `bevy`.
+//! The camera's projection, including the `scale` of its `OrthographicProjection`,
+//! and its [`GlobalTransform`] are both accounted for.
+//!
+//! Requires the `camera` feature.
+
+use crate::coordinate::Coordinate;
+use crate::position::{Position, Positionlike};
+use bevy_math::Vec2;
+use bevy_render::camera::Camera;
+use bevy_transform::components::GlobalTransform;
+use bevy_window::Window;
+
+/// The rectangular region of a window that a camera renders to
+///
+/// Measured in logical pixels from the bottom-left corner of the window.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Viewport {
+    /// The bottom-left corner of the viewport
+    pub origin: Vec2,
+    /// The width and height of the viewport
+    pub size: Vec2,
+}
+
+impl Viewport {
+    /// Creates a new viewport with its bottom-left corner at `origin`
+    ///
+    /// # Panics
+    /// Both dimensions of `size` must be greater than zero.
+    #[inline]
+    #[must_use]
+    pub fn new(origin: Vec2, size: Vec2) -> Self {
+        assert!(size.x > 0. && size.y > 0.);
+
+        Self { origin, size }
+    }
+
+    /// A viewport that covers the entire `window`
+    ///
+    /// Returns `None` if the window has no area, such as when it is minimized.
+    #[must_use]
+    pub fn from_window(window: &Window) -> Option<Self> {
+        let size = Vec2::new(window.width(), window.height());
+
+        (size.x > 0. && size.y > 0.).then_some(Self {
+            origin: Vec2::ZERO,
+            size,
+        })
+    }
+
+    /// Does this viewport contain the `screen_position`, including its edges?
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, screen_position: Vec2) -> bool {
+        let relative = screen_position - self.origin;
+
+        relative.cmpge(Vec2::ZERO).all() && relative.cmple(self.size).all()
+    }
+}
+
+/// Converts a point in screen space into world space, as seen through the `camera`
+///
+/// Returns `None` if the point lies outside of the `viewport`.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy::render::camera::{Camera, CameraProjection};
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::position::Position;
+/// use leafwing_2d::screen::{screen_to_world, Viewport};
+///
+/// let mut projection = OrthographicProjection::default();
+/// projection.update(800.0, 600.0);
+/// let camera = Camera {
+///     projection_matrix: projection.get_projection_matrix(),
+///     ..Default::default()
+/// };
+/// let camera_transform = GlobalTransform::from_xyz(100.0, 0.0, 0.0);
+/// let viewport = Viewport::new(Vec2::ZERO, Vec2::new(800.0, 600.0));
+///
+/// // The center of the screen is wherever the camera is
+/// let position: Position<F32> =
+///     screen_to_world(Vec2::new(400.0, 300.0), &viewport, &camera, &camera_transform).unwrap();
+/// assert_eq!(position, Position::new(100.0, 0.0));
+/// ```
+#[must_use]
+pub fn screen_to_world<C: Coordinate>(
+    screen_position: Vec2,
+    viewport: &Viewport,
+    camera: &Camera,
+    camera_transform: &GlobalTransform,
+) -> Option<Position<C>> {
+    if !viewport.contains(screen_position) {
+        return None;
+    }
+
+    let ndc = (screen_position - viewport.origin) / viewport.size * 2. - Vec2::ONE;
+    let ndc_to_world = camera_transform.compute_matrix() * camera.projection_matrix.inverse();
+    let world_position = ndc_to_world.project_point3(ndc.extend(-1.)).truncate();
+
+    world_position.is_finite().then(|| world_position.into())
+}
+
+/// Converts a point in world space into screen space, as seen through the `camera`
+///
+/// Points outside of the camera's view are still converted,
+/// and will lie outside of the `viewport`.
+/// This is useful for placing UI elements over entities in the world.
+#[must_use]
+pub fn world_to_screen(
+    world_position: impl Positionlike,
+    viewport: &Viewport,
+    camera: &Camera,
+    camera_transform: &GlobalTransform,
+) -> Vec2 {
+    let world_to_ndc = camera.projection_matrix * camera_transform.compute_matrix().inverse();
+    let ndc = world_to_ndc
+        .project_point3(world_position.into_vec2().extend(0.))
+        .truncate();
+
+    (ndc + Vec2::ONE) / 2. * viewport.size + viewport.origin
+}
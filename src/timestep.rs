@@ -0,0 +1,240 @@
+//! Tools for running kinematics in fixed-size steps, and smoothly rendering the results
+
+use crate::coordinate::Coordinate;
+use crate::orientation::Rotation;
+use crate::position::Position;
+use bevy_core::Time;
+use bevy_ecs::prelude::*;
+use bevy_ecs::schedule::ShouldRun;
+use bevy_math::{Quat, Vec2};
+use bevy_transform::components::Transform;
+use core::time::Duration;
+
+/// Runs [`TwoDSystem::Kinematics`](crate::plugin::TwoDSystem::Kinematics) in steps of a fixed length,
+/// rather than once per frame
+///
+/// Elapsed [`Time`] is stored in an accumulator, and kinematics are run once for each full `step` it contains.
+/// Any leftover time is carried over to the next frame.
+/// This makes the simulation independent of the frame rate.
+///
+/// To avoid a spiral of ever-longer frames after a stall (such as a breakpoint or dragging the window),
+/// at most [`FixedTimestep::max_steps_per_frame`] steps are run each frame, and any excess time is dropped.
+///
+/// This resource is inserted by [`TwoDPlugin`](crate::plugin::TwoDPlugin) when its `fixed_timestep` field is set.
+/// Add the [`Interpolated`] component to entities to smooth out their movement between steps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixedTimestep {
+    step: Duration,
+    accumulator: Duration,
+    max_steps_per_frame: u32,
+    looping: bool,
+}
+
+impl FixedTimestep {
+    /// The default value of [`FixedTimestep::max_steps_per_frame`]
+    pub const DEFAULT_MAX_STEPS_PER_FRAME: u32 = 8;
+
+    /// Creates a new fixed timestep, which runs kinematics once for every `step` of elapsed time
+    ///
+    /// At most [`FixedTimestep::DEFAULT_MAX_STEPS_PER_FRAME`] steps are run each frame.
+    ///
+    /// # Panics
+    /// `step` must be greater than zero.
+    #[inline]
+    #[must_use]
+    pub fn new(step: Duration) -> Self {
+        assert!(step > Duration::ZERO);
+
+        Self {
+            step,
+            accumulator: Duration::ZERO,
+            max_steps_per_frame: Self::DEFAULT_MAX_STEPS_PER_FRAME,
+            looping: false,
+        }
+    }
+
+    /// Creates a new fixed timestep that runs kinematics `steps_per_second` times per second
+    ///
+    /// # Panics
+    /// `steps_per_second` must be greater than zero.
+    #[inline]
+    #[must_use]
+    pub fn from_steps_per_second(steps_per_second: f64) -> Self {
+        assert!(steps_per_second > 0.);
+
+        Self::new(Duration::from_secs_f64(1. / steps_per_second))
+    }
+
+    /// Sets the largest number of steps that can be run in a single frame
+    ///
+    /// # Panics
+    /// `max_steps_per_frame` must be greater than zero.
+    #[inline]
+    #[must_use]
+    pub fn with_max_steps_per_frame(mut self, max_steps_per_frame: u32) -> Self {
+        assert!(max_steps_per_frame > 0);
+
+        self.max_steps_per_frame = max_steps_per_frame;
+        self.accumulator = self.accumulator.min(self.max_accumulated());
+        self
+    }
+
+    /// The length of each step
+    #[inline]
+    #[must_use]
+    pub fn step(&self) -> Duration {
+        self.step
+    }
+
+    /// The largest number of steps that can be run in a single frame
+    #[inline]
+    #[must_use]
+    pub fn max_steps_per_frame(&self) -> u32 {
+        self.max_steps_per_frame
+    }
+
+    /// The elapsed time that has not yet been consumed by a step
+    #[inline]
+    #[must_use]
+    pub fn accumulated(&self) -> Duration {
+        self.accumulator
+    }
+
+    /// How far the accumulated time is through the next step, between 0 and 1
+    ///
+    /// This is used to interpolate between the previous and current state of [`Interpolated`] entities.
+    #[inline]
+    #[must_use]
+    pub fn overstep_fraction(&self) -> f32 {
+        self.accumulator.as_secs_f32() / self.step.as_secs_f32()
+    }
+
+    /// Adds `elapsed` time to the accumulator, to be simulated during the next frame
+    ///
+    /// This happens automatically with the time elapsed each frame,
+    /// but can be used to simulate extra time, such as when stepping through a replay.
+    /// Any time beyond [`FixedTimestep::max_steps_per_frame`] steps is dropped.
+    pub fn accumulate(&mut self, elapsed: Duration) {
+        self.accumulator = (self.accumulator + elapsed).min(self.max_accumulated());
+    }
+
+    /// Are more steps due to run this frame?
+    #[inline]
+    pub(crate) fn is_stepping(&self) -> bool {
+        self.looping
+    }
+
+    /// The most time that can be stored in the accumulator
+    #[inline]
+    fn max_accumulated(&self) -> Duration {
+        self.step * self.max_steps_per_frame
+    }
+
+    /// Adds the time elapsed this frame to the accumulator, then consumes one step if possible
+    ///
+    /// Intended for use as a run criteria: the elapsed time is only added on the first call each frame,
+    /// and [`ShouldRun::YesAndCheckAgain`] is returned until the accumulator runs dry.
+    pub(crate) fn advance(&mut self, delta: Duration) -> ShouldRun {
+        if !self.looping {
+            self.accumulate(delta);
+        }
+
+        if self.accumulator >= self.step {
+            self.accumulator -= self.step;
+            self.looping = true;
+            ShouldRun::YesAndCheckAgain
+        } else {
+            self.looping = false;
+            ShouldRun::No
+        }
+    }
+}
+
+/// Smooths the rendered [`Transform`] of an entity whose kinematics run with a [`FixedTimestep`]
+///
+/// Stores the entity's [`Position`] and [`Rotation`] from before the latest fixed step.
+/// Each frame, its [`Transform`] is interpolated between this previous state and the current one,
+/// based on [`FixedTimestep::overstep_fraction`].
+/// As a result, rendering lags up to one step behind the simulation.
+///
+/// The [`Transform`] of these entities is driven entirely by their 2D components:
+/// changes made directly to the [`Transform`] are not copied back to the [`Position`] or [`Rotation`].
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+pub struct Interpolated<C: Coordinate> {
+    /// The [`Position`] of this entity before the latest fixed step
+    pub previous_position: Position<C>,
+    /// The [`Rotation`] of this entity before the latest fixed step
+    pub previous_rotation: Rotation,
+}
+
+/// Records the [`Position`] and [`Rotation`] of each [`Interpolated`] entity before a fixed step is run
+pub fn record_previous_state<C: Coordinate>(
+    mut query: Query<(
+        &mut Interpolated<C>,
+        Option<&Position<C>>,
+        Option<&Rotation>,
+    )>,
+) {
+    for (mut interpolated, maybe_position, maybe_rotation) in query.iter_mut() {
+        if let Some(&position) = maybe_position {
+            interpolated.previous_position = position;
+        }
+
+        if let Some(&rotation) = maybe_rotation {
+            interpolated.previous_rotation = rotation;
+        }
+    }
+}
+
+/// Sets the [`Transform`] of each [`Interpolated`] entity partway between its previous and current state
+///
+/// This must run after every fixed step for the frame is complete.
+/// Without a [`FixedTimestep`] resource, the current state is used directly.
+pub fn interpolate_transforms<C: Coordinate>(
+    fixed_timestep: Option<Res<FixedTimestep>>,
+    mut query: Query<(
+        &mut Transform,
+        &mut Interpolated<C>,
+        Option<&Position<C>>,
+        Option<&Rotation>,
+    )>,
+) {
+    let alpha = fixed_timestep.map_or(1., |fixed_timestep| fixed_timestep.overstep_fraction());
+    // This runs after the last step of the frame, so less than a whole step is left over
+    debug_assert!(alpha <= 1.);
+
+    for (mut transform, mut interpolated, maybe_position, maybe_rotation) in query.iter_mut() {
+        // Newly added entities have no meaningful previous state yet
+        if interpolated.is_added() {
+            interpolated.previous_position = maybe_position.copied().unwrap_or_default();
+            interpolated.previous_rotation = maybe_rotation.copied().unwrap_or_default();
+        }
+
+        if let Some(&position) = maybe_position {
+            let previous = Vec2::from(interpolated.previous_position);
+            let new_translation = previous.lerp(position.into(), alpha);
+
+            // Avoid triggering change detection needlessly
+            if transform.translation.truncate() != new_translation {
+                transform.translation.x = new_translation.x;
+                transform.translation.y = new_translation.y;
+            }
+        }
+
+        if let Some(&rotation) = maybe_rotation {
+            let previous: Quat = interpolated.previous_rotation.into();
+            let new_rotation = previous.slerp(rotation.into(), alpha);
+
+            if transform.rotation != new_rotation {
+                transform.rotation = new_rotation;
+            }
+        }
+    }
+}
+
+/// The amount of time that a single run of the kinematics systems covers
+///
+/// This is the length of a step when a [`FixedTimestep`] exists, or the time elapsed this frame otherwise.
+pub(crate) fn kinematics_delta(time: &Time, fixed_timestep: Option<&FixedTimestep>) -> Duration {
+    fixed_timestep.map_or_else(|| time.delta(), FixedTimestep::step)
+}
@@ -0,0 +1,183 @@
+//! Regions that track which entities are inside of them, for "when the player enters this area" behavior
+
+use crate::bounding::{BoundingRegion, CollisionLayers};
+use crate::position::Position;
+
+use bevy_ecs::prelude::*;
+use bevy_ecs::query::{FilterFetch, WorldQuery};
+use core::marker::PhantomData;
+use std::collections::HashSet;
+
+/// A [`BoundingRegion`] that tracks which entities with a [`Position`] are inside of it
+///
+/// Each time an entity's [`Position`] moves into or out of the `region`,
+/// a [`TriggerEntered`] or [`TriggerExited`] event is sent.
+/// Entities that are despawned or lose their [`Position`] while inside the zone are treated as having exited.
+///
+/// Only entities that match the query filter `F` can occupy the zone.
+/// If the zone entity has [`CollisionLayers`], only entities whose layers interact with it can occupy the zone.
+///
+/// Zones with the default filter and the built-in bounding regions are updated by [`CollisionPlugin`](crate::collision::CollisionPlugin).
+/// For other combinations, add [`update_trigger_zones::<R, F>`] to your app in the [`TwoDSystem::TriggerZones`](crate::plugin::TwoDSystem::TriggerZones) label.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_2d::collision::CollisionPlugin;
+/// use leafwing_2d::plugin::TwoDSystem;
+/// use leafwing_2d::prelude::*;
+/// use leafwing_2d::trigger::{update_trigger_zones, TriggerEntered, TriggerZone};
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// type Checkpoint = TriggerZone<BoundingCircle<F32>, With<Player>>;
+///
+/// fn save_at_checkpoint(mut events: EventReader<TriggerEntered>) {
+///     for event in events.iter() {
+///         println!("{:?} reached checkpoint {:?}", event.entity, event.zone);
+///     }
+/// }
+///
+/// let mut app = App::new();
+/// app.insert_resource(bevy::core::Time::default())
+///     .add_plugin(TwoDPlugin::default())
+///     .add_plugin(CollisionPlugin::default())
+///     .add_system_to_stage(
+///         CoreStage::PostUpdate,
+///         update_trigger_zones::<BoundingCircle<F32>, With<Player>>
+///             .label(TwoDSystem::TriggerZones)
+///             .after(TwoDSystem::Kinematics),
+///     )
+///     .add_system(save_at_checkpoint);
+///
+/// app.world
+///     .spawn()
+///     .insert(Checkpoint::new(BoundingCircle::new(Position::new(10.0, 0.0), 2.0)));
+///
+/// app.update();
+/// ```
+#[derive(Component, Debug)]
+pub struct TriggerZone<R: BoundingRegion + Send + Sync + 'static, F: WorldQuery + 'static = ()> {
+    /// The region which entities must be inside to occupy this zone
+    pub region: R,
+    occupants: HashSet<Entity>,
+    _filter: PhantomData<fn() -> F>,
+}
+
+impl<R: BoundingRegion + Send + Sync + 'static, F: WorldQuery + 'static> TriggerZone<R, F> {
+    /// Creates a new, unoccupied zone covering `region`
+    #[inline]
+    #[must_use]
+    pub fn new(region: R) -> Self {
+        Self {
+            region,
+            occupants: HashSet::default(),
+            _filter: PhantomData,
+        }
+    }
+
+    /// Is `entity` currently inside this zone?
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.occupants.contains(&entity)
+    }
+
+    /// Iterates over the entities currently inside this zone, in no particular order
+    pub fn occupants(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.occupants.iter().copied()
+    }
+
+    /// The number of entities currently inside this zone
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.occupants.len()
+    }
+
+    /// Is this zone unoccupied?
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.occupants.is_empty()
+    }
+}
+
+impl<R: BoundingRegion + Clone + Send + Sync + 'static, F: WorldQuery + 'static> Clone
+    for TriggerZone<R, F>
+{
+    fn clone(&self) -> Self {
+        Self {
+            region: self.region.clone(),
+            occupants: self.occupants.clone(),
+            _filter: PhantomData,
+        }
+    }
+}
+
+/// Sent when an `entity` moves into a [`TriggerZone`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TriggerEntered {
+    /// The entity with the [`TriggerZone`] component
+    pub zone: Entity,
+    /// The entity that entered the zone
+    pub entity: Entity,
+}
+
+/// Sent when an `entity` leaves a [`TriggerZone`], or is despawned while inside of it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TriggerExited {
+    /// The entity with the [`TriggerZone`] component
+    pub zone: Entity,
+    /// The entity that left the zone
+    pub entity: Entity,
+}
+
+/// Updates the occupants of each [`TriggerZone<R, F>`], sending [`TriggerEntered`] and [`TriggerExited`] events
+pub fn update_trigger_zones<R, F>(
+    mut zone_query: Query<(Entity, &mut TriggerZone<R, F>, Option<&CollisionLayers>)>,
+    occupant_query: Query<(Entity, &Position<R::C>, Option<&CollisionLayers>), F>,
+    mut entered: EventWriter<TriggerEntered>,
+    mut exited: EventWriter<TriggerExited>,
+) where
+    R: BoundingRegion + Send + Sync + 'static,
+    F: WorldQuery + 'static,
+    F::Fetch: FilterFetch,
+{
+    for (zone, mut trigger_zone, maybe_zone_layers) in zone_query.iter_mut() {
+        let zone_layers = maybe_zone_layers.copied().unwrap_or_default();
+
+        let current: HashSet<Entity> = occupant_query
+            .iter()
+            .filter(|&(entity, &position, maybe_layers)| {
+                entity != zone
+                    && zone_layers.interacts_with(&maybe_layers.copied().unwrap_or_default())
+                    && trigger_zone.region.contains(position)
+            })
+            .map(|(entity, ..)| entity)
+            .collect();
+
+        // Sorting keeps the order of events deterministic
+        let mut new_occupants: Vec<_> = current.difference(&trigger_zone.occupants).collect();
+        new_occupants.sort_unstable();
+        entered.send_batch(
+            new_occupants
+                .into_iter()
+                .map(|&entity| TriggerEntered { zone, entity }),
+        );
+
+        let mut old_occupants: Vec<_> = trigger_zone.occupants.difference(&current).collect();
+        old_occupants.sort_unstable();
+        exited.send_batch(
+            old_occupants
+                .into_iter()
+                .map(|&entity| TriggerExited { zone, entity }),
+        );
+
+        // Avoid triggering change detection needlessly
+        if trigger_zone.occupants != current {
+            trigger_zone.occupants = current;
+        }
+    }
+}
@@ -0,0 +1,1421 @@
+use bevy::ecs::system::SystemState;
+use bevy::prelude::*;
+use core::time::Duration;
+use leafwing_2d::bounding::*;
+use leafwing_2d::bundles::TwoDBundle;
+use leafwing_2d::continuous::F32;
+use leafwing_2d::errors::EmptyRegion;
+use leafwing_2d::kinematics::systems::continuous_linear_kinematics;
+use leafwing_2d::kinematics::{Acceleration, Velocity};
+use leafwing_2d::orientation::{Direction, Orientation};
+use leafwing_2d::plugin::TwoDPlugin;
+use leafwing_2d::position::{Position, Positionlike};
+use leafwing_2d::timestep::FixedTimestep;
+
+#[test]
+fn raycast_aabb() {
+    let aabb = AxisAlignedBoundingBox::<F32>::from_size(Position::new(10.0, 10.0), 2.0, 2.0);
+
+    let hit = aabb
+        .cast_ray(Ray::new(Position::new(10.0, 0.0), Direction::NORTH))
+        .unwrap();
+    assert_eq!(hit.distance, F32(8.0));
+    assert_eq!(hit.normal, Direction::SOUTH);
+    hit.point.assert_approx_eq(Position::<F32>::new(10.0, 8.0));
+
+    // Diagonal rays strike the nearest face
+    let hit = aabb
+        .cast_ray(Ray::new(Position::new(0.0, 2.0), Direction::NORTHEAST))
+        .unwrap();
+    assert_eq!(hit.normal, Direction::WEST);
+    hit.point.assert_approx_eq(Position::<F32>::new(8.0, 10.0));
+
+    // Misses
+    assert!(aabb
+        .cast_ray(Ray::new(Position::new(15.0, 0.0), Direction::NORTH))
+        .is_none());
+    assert!(aabb
+        .cast_ray(Ray::new(Position::new(10.0, 0.0), Direction::SOUTH))
+        .is_none());
+
+    // Starting inside
+    let hit = aabb
+        .cast_ray(Ray::new(Position::new(10.0, 10.0), Direction::EAST))
+        .unwrap();
+    assert_eq!(hit.distance, F32(0.0));
+    assert_eq!(hit.normal, Direction::WEST);
+}
+
+#[test]
+fn raycast_circle() {
+    let circle = BoundingCircle::<F32>::new(Position::new(10.0, 0.0), 2.0);
+
+    let hit = circle
+        .cast_ray(Ray::new(Position::default(), Direction::EAST))
+        .unwrap();
+    assert_eq!(hit.distance, F32(8.0));
+    assert_eq!(hit.normal, Direction::WEST);
+    hit.point.assert_approx_eq(Position::<F32>::new(8.0, 0.0));
+
+    assert!(circle
+        .cast_ray(Ray::new(Position::default(), Direction::NORTH))
+        .is_none());
+    assert!(circle
+        .cast_ray(Ray::new(Position::default(), Direction::WEST))
+        .is_none());
+}
+
+#[test]
+fn raycast_polygon() {
+    let triangle = BoundingPolygon::<F32>::new([
+        Position::new(0.0, 5.0),
+        Position::new(5.0, 5.0),
+        Position::new(0.0, 10.0),
+    ]);
+
+    let hit = triangle
+        .cast_ray(Ray::new(Position::new(1.0, 0.0), Direction::NORTH))
+        .unwrap();
+    assert_eq!(hit.distance, F32(5.0));
+    assert_eq!(hit.normal, Direction::SOUTH);
+
+    // Striking the slanted edge from outside
+    let hit = triangle
+        .cast_ray(Ray::new(Position::new(10.0, 7.5), Direction::WEST))
+        .unwrap();
+    hit.point.assert_approx_eq(Position::<F32>::new(2.5, 7.5));
+    hit.normal.assert_approx_eq(Direction::NORTHEAST);
+
+    assert!(triangle
+        .cast_ray(Ray::new(Position::new(10.0, 0.0), Direction::NORTH))
+        .is_none());
+}
+
+#[test]
+fn empty_polygon_contains_nothing() {
+    let empty = BoundingPolygon::<F32>::default();
+
+    assert!(!empty.contains(Position::default()));
+    assert!(!empty.contains(Position::new(3.0, -2.0)));
+    assert!(empty
+        .cast_ray(Ray::new(Position::default(), Direction::EAST))
+        .is_none());
+}
+
+#[test]
+fn raycast_query_finds_nearest() {
+    let mut world = World::new();
+
+    let far_wall = world
+        .spawn()
+        .insert(BoundingPolygon::<F32>::new([
+            Position::new(20.0, -5.0),
+            Position::new(21.0, -5.0),
+            Position::new(21.0, 5.0),
+            Position::new(20.0, 5.0),
+        ]))
+        .id();
+    let near_circle = world
+        .spawn()
+        .insert(BoundingCircle::<F32>::new(Position::new(10.0, 0.0), 1.0))
+        .id();
+    let _behind = world
+        .spawn()
+        .insert(BoundingCircle::<F32>::new(Position::new(-10.0, 0.0), 1.0))
+        .id();
+
+    let mut system_state: SystemState<RaycastQuery<F32>> = SystemState::new(&mut world);
+    let raycast = system_state.get_mut(&mut world);
+
+    let ray = Ray::new(Position::default(), Direction::EAST);
+    let (entity, hit) = raycast.cast_ray(ray).unwrap();
+    assert_eq!(entity, near_circle);
+    assert_eq!(hit.distance, F32(9.0));
+
+    let all_hits: Vec<Entity> = raycast
+        .cast_ray_all(ray)
+        .into_iter()
+        .map(|(entity, _)| entity)
+        .collect();
+    assert_eq!(all_hits, vec![near_circle, far_wall]);
+
+    assert!(raycast
+        .cast_ray(Ray::new(Position::default(), Direction::NORTH))
+        .is_none());
+}
+
+#[test]
+fn sweep_aabb() {
+    let bullet = AxisAlignedBoundingBox::<F32>::from_size(Position::new(0.0, 0.0), 1.0, 1.0);
+    // Spans x from 9 to 11, and y from 2 to 6
+    let wall = AxisAlignedBoundingBox::<F32>::from_size(Position::new(10.0, 4.0), 1.0, 2.0);
+
+    // The bullet lines up vertically with the wall before it reaches it horizontally
+    let impact = bullet.sweep(Position::new(100.0, 20.0), &wall).unwrap();
+    impact
+        .offset
+        .assert_approx_eq(Position::<F32>::new(8.0, 1.6));
+    assert_eq!(impact.normal, Direction::WEST);
+    assert!((impact.fraction - 0.08).abs() < 1e-4);
+
+    // Approaching from below strikes the bottom face instead
+    let ceiling = AxisAlignedBoundingBox::<F32>::from_size(Position::new(1.0, 10.0), 2.0, 1.0);
+    let impact = bullet.sweep(Position::new(3.0, 50.0), &ceiling).unwrap();
+    impact
+        .offset
+        .assert_approx_eq(Position::<F32>::new(0.48, 8.0));
+    assert_eq!(impact.normal, Direction::SOUTH);
+
+    // Too short to reach the wall
+    assert!(bullet.sweep(Position::new(5.0, 1.0), &wall).is_none());
+    // Moving away from the wall
+    assert!(bullet.sweep(Position::new(-100.0, -20.0), &wall).is_none());
+}
+
+#[test]
+fn sweep_polygon() {
+    let square = |x: f32, y: f32| {
+        BoundingPolygon::<F32>::new([
+            Position::new(x - 1.0, y - 1.0),
+            Position::new(x + 1.0, y - 1.0),
+            Position::new(x + 1.0, y + 1.0),
+            Position::new(x - 1.0, y + 1.0),
+        ])
+    };
+
+    let bullet = square(0.0, 0.0);
+    let wall = square(10.0, 0.0);
+
+    let impact = bullet.sweep(Position::new(100.0, 0.0), &wall).unwrap();
+    impact
+        .offset
+        .assert_approx_eq(Position::<F32>::new(8.0, 0.0));
+    assert_eq!(impact.normal, Direction::WEST);
+
+    // Already overlapping
+    let impact = bullet
+        .sweep(Position::new(1.0, 0.0), &square(1.0, 0.0))
+        .unwrap();
+    assert_eq!(impact.fraction, 0.0);
+}
+
+#[test]
+fn continuous_collision_prevents_tunneling() {
+    let mut app = App::new();
+    app.insert_resource(Time::default()).add_plugin(TwoDPlugin {
+        fixed_timestep: Some(Duration::from_millis(10)),
+        ..Default::default()
+    });
+
+    let bullet = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Position::<F32>::default(),
+            Velocity::<F32> {
+                x: F32(100_000.0),
+                y: F32(20_000.0),
+            },
+            Acceleration::<F32>::default(),
+            AxisAlignedBoundingBox::<F32>::from_size(Position::default(), 1.0, 1.0),
+            ContinuousCollision,
+        ))
+        .id();
+
+    app.world
+        .spawn()
+        .insert(AxisAlignedBoundingBox::<F32>::from_size(
+            Position::new(10.0, 4.0),
+            1.0,
+            2.0,
+        ));
+
+    // `Time` is never updated, so only the time added here is simulated
+    // Without continuous collision, a single step would move the bullet 1000 units
+    app.world
+        .resource_mut::<FixedTimestep>()
+        .accumulate(Duration::from_millis(10));
+    app.update();
+
+    let position = *app.world.get::<Position<F32>>(bullet).unwrap();
+    position.assert_approx_eq(Position::<F32>::new(8.0, 1.6));
+
+    let velocity = *app.world.get::<Velocity<F32>>(bullet).unwrap();
+    assert_eq!(velocity, Velocity::default());
+}
+
+#[test]
+fn continuous_collision_without_bounds() {
+    let mut app = App::new();
+    app.insert_resource(Time::default())
+        .insert_resource(FixedTimestep::from_steps_per_second(10.))
+        .add_system(continuous_linear_kinematics::<F32>);
+
+    // Swept as a single point
+    let bullet = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Position::<F32>::new(-5.0, 0.0),
+            Velocity::<F32> {
+                x: F32(200.0),
+                y: F32(0.0),
+            },
+            Acceleration::<F32>::default(),
+            ContinuousCollision,
+        ))
+        .id();
+
+    // The empty default bounding box of a `TwoDBundle` is not an obstacle
+    app.world
+        .spawn()
+        .insert_bundle(TwoDBundle::<F32>::default());
+    app.world
+        .spawn()
+        .insert(AxisAlignedBoundingBox::<F32>::from_size(
+            Position::new(10.0, 1.0),
+            1.0,
+            2.0,
+        ));
+
+    app.update();
+
+    let position = *app.world.get::<Position<F32>>(bullet).unwrap();
+    position.assert_approx_eq(Position::<F32>::new(9.0, 0.0));
+    assert_eq!(
+        *app.world.get::<Velocity<F32>>(bullet).unwrap(),
+        Velocity::default()
+    );
+}
+
+#[test]
+fn aabb_measurements() {
+    let aabb = AxisAlignedBoundingBox::<F32>::new(-1.0, 3.0, 2.0, 4.0);
+
+    assert_eq!(aabb.width(), F32(4.0));
+    assert_eq!(aabb.height(), F32(2.0));
+    assert_eq!(aabb.area(), F32(8.0));
+    assert_eq!(aabb.center(), Position::new(1.0, 3.0));
+    assert_eq!(aabb.sprite_size(), Vec2::new(4.0, 2.0));
+
+    assert_eq!(
+        aabb.expand(1.0),
+        AxisAlignedBoundingBox::new(-2.0, 4.0, 1.0, 5.0)
+    );
+}
+
+#[test]
+fn aabb_translation() {
+    let aabb = AxisAlignedBoundingBox::<F32>::new(0.0, 1.0, 0.0, 1.0);
+    let offset = Position::new(2.0, -3.0);
+
+    assert_eq!(
+        aabb.clone() + offset,
+        AxisAlignedBoundingBox::new(2.0, 3.0, -3.0, -2.0)
+    );
+    assert_eq!(aabb.clone() + offset - offset, aabb);
+
+    assert_eq!(
+        aabb.translated(offset),
+        AxisAlignedBoundingBox::new(2.0, 3.0, -3.0, -2.0)
+    );
+}
+
+#[test]
+fn aabb_conversions() {
+    let aabb = AxisAlignedBoundingBox::<F32>::new(-1.0, 3.0, 2.0, 4.0);
+
+    let rect: Rect<f32> = aabb.clone().into();
+    assert_eq!(rect.left, -1.0);
+    assert_eq!(rect.right, 3.0);
+    assert_eq!(rect.bottom, 2.0);
+    assert_eq!(rect.top, 4.0);
+    assert_eq!(AxisAlignedBoundingBox::<F32>::from(rect), aabb);
+
+    let from_sprite = AxisAlignedBoundingBox::<F32>::from_sprite_size(
+        Position::new(1.0, 3.0),
+        Vec2::new(4.0, 2.0),
+    );
+    assert_eq!(from_sprite, aabb);
+}
+
+#[test]
+fn aabb_draw_around() {
+    // Boxes which do not include the origin
+    let aabb = AxisAlignedBoundingBox::<F32>::draw_around([
+        Position::new(5.0, 7.0),
+        Position::new(8.0, 6.0),
+        Position::new(6.0, 9.0),
+    ]);
+    assert_eq!(aabb, AxisAlignedBoundingBox::new(5.0, 8.0, 6.0, 9.0));
+
+    let aabb = AxisAlignedBoundingBox::<F32>::draw_around([
+        Position::new(-5.0, -7.0),
+        Position::new(-8.0, -6.0),
+    ]);
+    assert_eq!(aabb, AxisAlignedBoundingBox::new(-8.0, -5.0, -7.0, -6.0));
+
+    // A single position produces a box with no area
+    let single = Position::<F32>::new(3.0, -2.0);
+    assert_eq!(
+        AxisAlignedBoundingBox::draw_around([single]),
+        AxisAlignedBoundingBox::from(single)
+    );
+
+    // Empty inputs
+    assert_eq!(
+        AxisAlignedBoundingBox::<F32>::try_draw_around([]),
+        Err(EmptyRegion)
+    );
+    assert_eq!(
+        AxisAlignedBoundingBox::<F32>::draw_around([]),
+        AxisAlignedBoundingBox::default()
+    );
+}
+
+#[test]
+fn aabb_draw_around_contains_all_positions() {
+    // A small xorshift generator, so that this test is reproducible
+    let mut state: u32 = 0x2545_f491;
+    let mut next_coordinate = move || {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        (state % 2001) as f32 / 10.0 - 100.0
+    };
+
+    for n_positions in 1..=64 {
+        let positions: Vec<Position<F32>> = (0..n_positions)
+            .map(|_| Position::new(next_coordinate(), next_coordinate()))
+            .collect();
+
+        let aabb = AxisAlignedBoundingBox::try_draw_around(positions.iter().cloned()).unwrap();
+
+        assert!(aabb.left <= aabb.right);
+        assert!(aabb.bottom <= aabb.top);
+
+        for &position in &positions {
+            assert!(aabb.contains(position), "{position:?} is not in {aabb:?}");
+        }
+
+        // The box is tight: every side touches at least one of the positions
+        assert!(positions.iter().any(|position| position.x == aabb.left));
+        assert!(positions.iter().any(|position| position.x == aabb.right));
+        assert!(positions.iter().any(|position| position.y == aabb.bottom));
+        assert!(positions.iter().any(|position| position.y == aabb.top));
+    }
+}
+
+#[test]
+fn aabb_intersections() {
+    let aabb = AxisAlignedBoundingBox::<F32>::new(0.0, 4.0, 0.0, 4.0);
+
+    let inner = AxisAlignedBoundingBox::<F32>::new(1.0, 2.0, 1.0, 4.0);
+    assert_eq!(aabb.intersects(inner.clone()), Intersects::Contains);
+    assert_eq!(inner.intersects(aabb.clone()), Intersects::ContainedBy);
+    assert_eq!(aabb.intersects(aabb.clone()), Intersects::Contains);
+
+    let overlapping = AxisAlignedBoundingBox::<F32>::new(3.0, 5.0, -1.0, 1.0);
+    assert_eq!(aabb.intersects(overlapping), Intersects::Overlapping);
+
+    let beside = AxisAlignedBoundingBox::<F32>::new(4.0, 6.0, 1.0, 2.0);
+    assert_eq!(aabb.intersects(beside.clone()), Intersects::Touching);
+    assert_eq!(beside.intersects(aabb.clone()), Intersects::Touching);
+
+    let corner = AxisAlignedBoundingBox::<F32>::new(-2.0, 0.0, -2.0, 0.0);
+    assert_eq!(aabb.intersects(corner), Intersects::Touching);
+
+    let distant = AxisAlignedBoundingBox::<F32>::new(5.0, 6.0, 5.0, 6.0);
+    assert_eq!(aabb.intersects(distant), Intersects::No);
+}
+
+#[test]
+fn circle_intersections() {
+    let circle = BoundingCircle::<F32>::new(Position::new(0.0, 0.0), 4.0);
+
+    let inner = BoundingCircle::<F32>::new(Position::new(1.0, 0.0), 3.0);
+    assert_eq!(circle.intersects(inner.clone()), Intersects::Contains);
+    assert_eq!(inner.intersects(circle.clone()), Intersects::ContainedBy);
+
+    let overlapping = BoundingCircle::<F32>::new(Position::new(5.0, 0.0), 2.0);
+    assert_eq!(circle.intersects(overlapping), Intersects::Overlapping);
+
+    let touching = BoundingCircle::<F32>::new(Position::new(0.0, 6.0), 2.0);
+    assert_eq!(circle.intersects(touching), Intersects::Touching);
+
+    let distant = BoundingCircle::<F32>::new(Position::new(10.0, 0.0), 2.0);
+    assert_eq!(circle.intersects(distant), Intersects::No);
+}
+
+#[test]
+fn polygon_intersections() {
+    let square = |left: f32, bottom: f32, size: f32| {
+        BoundingPolygon::<F32>::new([
+            Position::new(left, bottom),
+            Position::new(left + size, bottom),
+            Position::new(left + size, bottom + size),
+            Position::new(left, bottom + size),
+        ])
+    };
+    let polygon = square(0.0, 0.0, 4.0);
+
+    assert_eq!(
+        polygon.intersects(square(1.0, 1.0, 2.0)),
+        Intersects::Contains
+    );
+    assert_eq!(
+        square(1.0, 1.0, 2.0).intersects(polygon.clone()),
+        Intersects::ContainedBy
+    );
+    assert_eq!(
+        polygon.intersects(square(3.0, 3.0, 2.0)),
+        Intersects::Overlapping
+    );
+    assert_eq!(
+        polygon.intersects(square(4.0, 1.0, 2.0)),
+        Intersects::Touching
+    );
+    assert_eq!(polygon.intersects(square(5.0, 5.0, 1.0)), Intersects::No);
+
+    let relationship = polygon.intersects(square(4.0, 1.0, 2.0));
+    assert!(relationship.is_intersecting());
+    assert!(!relationship.is_overlapping());
+    assert_eq!(Intersects::Contains.reversed(), Intersects::ContainedBy);
+}
+
+#[test]
+fn proximity_between_shapes() {
+    let aabb = AxisAlignedBoundingBox::<F32>::new(0.0, 2.0, 0.0, 2.0);
+
+    // Separated
+    let distant_aabb = AxisAlignedBoundingBox::<F32>::new(5.0, 6.0, 0.0, 2.0);
+    assert_eq!(aabb.signed_distance(&distant_aabb), F32(3.0));
+    assert_eq!(aabb.closest_point(&distant_aabb).x, F32(2.0));
+    assert_eq!(aabb.minimum_translation(&distant_aabb), None);
+
+    let circle = BoundingCircle::<F32>::new(Position::new(1.0, -3.0), 1.0);
+    assert_eq!(circle.signed_distance(&aabb), F32(2.0));
+    assert_eq!(aabb.signed_distance(&circle), F32(2.0));
+    assert_eq!(circle.closest_point(&aabb), Position::new(1.0, -2.0));
+    assert_eq!(aabb.closest_point(&circle), Position::new(1.0, 0.0));
+
+    let triangle = BoundingPolygon::<F32>::new([
+        Position::new(0.0, 0.0),
+        Position::new(4.0, 0.0),
+        Position::new(0.0, 4.0),
+    ]);
+    let below = BoundingCircle::<F32>::new(Position::new(2.0, -3.0), 1.0);
+    assert_eq!(triangle.signed_distance(&below), F32(2.0));
+    assert_eq!(triangle.closest_point(&below), Position::new(2.0, 0.0));
+
+    // Touching
+    let beside = AxisAlignedBoundingBox::<F32>::new(2.0, 3.0, 0.0, 1.0);
+    assert_eq!(aabb.signed_distance(&beside), F32(0.0));
+    assert_eq!(aabb.minimum_translation(&beside), None);
+
+    // Overlapping
+    let large = AxisAlignedBoundingBox::<F32>::new(0.0, 4.0, 0.0, 4.0);
+    let overlapping = AxisAlignedBoundingBox::<F32>::new(3.0, 6.0, 1.0, 2.0);
+    assert_eq!(large.signed_distance(&overlapping), F32(-1.0));
+    assert_eq!(
+        large.minimum_translation(&overlapping),
+        Some(Position::new(-1.0, 0.0))
+    );
+    assert_eq!(large.closest_point(&overlapping).x, F32(4.0));
+
+    let left = BoundingCircle::<F32>::new(Position::new(0.0, 0.0), 2.0);
+    let right = BoundingCircle::<F32>::new(Position::new(3.0, 0.0), 2.0);
+    assert_eq!(left.signed_distance(&right), F32(-1.0));
+    assert_eq!(
+        left.minimum_translation(&right),
+        Some(Position::new(-1.0, 0.0))
+    );
+    assert_eq!(left.closest_point(&right), Position::new(2.0, 0.0));
+}
+
+#[test]
+fn proximity_to_positions() {
+    let aabb = AxisAlignedBoundingBox::<F32>::new(0.0, 2.0, 0.0, 2.0);
+
+    let outside = Position::<F32>::new(5.0, 1.0);
+    assert_eq!(aabb.signed_distance(&outside), F32(3.0));
+    assert_eq!(aabb.closest_point(&outside), Position::new(2.0, 1.0));
+    assert_eq!(aabb.minimum_translation(&outside), None);
+
+    let inside = Position::<F32>::new(1.5, 1.0);
+    assert_eq!(aabb.signed_distance(&inside), F32(-0.5));
+    assert_eq!(
+        aabb.minimum_translation(&inside),
+        Some(Position::new(-0.5, 0.0))
+    );
+
+    let circle = BoundingCircle::<F32>::new(Position::new(0.0, 0.0), 1.0);
+    assert_eq!(circle.signed_distance(&Position::new(0.0, 3.0)), F32(2.0));
+    assert_eq!(
+        circle.closest_point(&Position::new(0.0, 3.0)),
+        Position::new(0.0, 1.0)
+    );
+}
+
+#[test]
+fn intersections_across_shapes() {
+    let aabb = AxisAlignedBoundingBox::<F32>::new(0.0, 4.0, 0.0, 4.0);
+    let inner_circle = BoundingCircle::<F32>::new(Position::new(2.0, 2.0), 1.0);
+    let touching_circle = BoundingCircle::<F32>::new(Position::new(5.0, 2.0), 1.0);
+    let overlapping_triangle = BoundingPolygon::<F32>::new([
+        Position::new(3.0, 3.0),
+        Position::new(6.0, 3.0),
+        Position::new(3.0, 6.0),
+    ]);
+
+    assert_eq!(aabb.intersects_with(&inner_circle), Intersects::Contains);
+    assert_eq!(inner_circle.intersects_with(&aabb), Intersects::ContainedBy);
+    assert_eq!(aabb.intersects_with(&touching_circle), Intersects::Touching);
+    assert_eq!(
+        overlapping_triangle.intersects_with(&aabb),
+        Intersects::Overlapping
+    );
+    assert_eq!(
+        touching_circle.intersects_with(&Position::new(5.0, 2.0)),
+        Intersects::Contains
+    );
+    assert_eq!(
+        inner_circle.intersects_with(&overlapping_triangle),
+        Intersects::No
+    );
+
+    // Colliders of different kinds can be compared directly
+    let colliders: Vec<Collider<F32>> = vec![
+        aabb.clone().into(),
+        inner_circle.clone().into(),
+        touching_circle.into(),
+        overlapping_triangle.into(),
+    ];
+    let relationships: Vec<Intersects> = colliders
+        .iter()
+        .map(|collider| colliders[0].intersects(collider.clone()))
+        .collect();
+    assert_eq!(
+        relationships,
+        vec![
+            Intersects::Contains,
+            Intersects::Contains,
+            Intersects::Touching,
+            Intersects::Overlapping
+        ]
+    );
+
+    assert_eq!(colliders[1].bounding_box(), inner_circle.bounding_box());
+    assert!(colliders[1].contains(Position::new(2.5, 2.5)));
+}
+
+#[test]
+fn local_bounds_follow_transform() {
+    use leafwing_2d::orientation::Rotation;
+    use leafwing_2d::scale::Scale;
+
+    let mut app = App::new();
+    app.insert_resource(Time::default())
+        .add_plugin(TwoDPlugin::default());
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Position::<F32>::new(10.0, 10.0),
+            Rotation::NORTH,
+            Scale::<F32>(1.0),
+            AxisAlignedBoundingBox::<F32>::default(),
+            LocalBoundingBox::<F32>::from_size(2.0, 1.0),
+        ))
+        .id();
+
+    app.update();
+    assert_eq!(
+        *app.world
+            .get::<AxisAlignedBoundingBox<F32>>(entity)
+            .unwrap(),
+        AxisAlignedBoundingBox::new(8.0, 12.0, 9.0, 11.0)
+    );
+
+    *app.world.get_mut::<Position<F32>>(entity).unwrap() = Position::new(0.0, 0.0);
+    *app.world.get_mut::<Scale<F32>>(entity).unwrap() = Scale(2.0);
+    app.update();
+    assert_eq!(
+        *app.world
+            .get::<AxisAlignedBoundingBox<F32>>(entity)
+            .unwrap(),
+        AxisAlignedBoundingBox::new(-4.0, 4.0, -2.0, 2.0)
+    );
+
+    // A quarter turn swaps the width and height
+    *app.world.get_mut::<Rotation>(entity).unwrap() = Rotation::EAST;
+    app.update();
+    let aabb = app
+        .world
+        .get::<AxisAlignedBoundingBox<F32>>(entity)
+        .unwrap()
+        .clone();
+    assert!((aabb.width() - F32(4.0)).0.abs() < 1e-4);
+    assert!((aabb.height() - F32(8.0)).0.abs() < 1e-4);
+}
+
+#[test]
+fn negative_scale_mirrors_local_bounds() {
+    use leafwing_2d::orientation::Rotation;
+    use leafwing_2d::scale::Scale;
+
+    let local = LocalBoundingBox(AxisAlignedBoundingBox::<F32>::new(0.0, 2.0, -1.0, 3.0));
+
+    let world = local.world_bounds(Position::new(10.0, 10.0), None, Some(&Scale(-2.0)));
+    assert_eq!(world, AxisAlignedBoundingBox::new(6.0, 10.0, 4.0, 12.0));
+    assert!(world.left <= world.right && world.bottom <= world.top);
+
+    // Rotated boxes are mirrored in the same way
+    let rotated = local.world_bounds(
+        Position::new(10.0, 10.0),
+        Some(&Rotation::SOUTH),
+        Some(&Scale(-2.0)),
+    );
+    assert!(rotated.left <= rotated.right && rotated.bottom <= rotated.top);
+    assert!((rotated.width() - F32(4.0)).0.abs() < 1e-4);
+    assert!((rotated.height() - F32(8.0)).0.abs() < 1e-4);
+}
+
+#[test]
+#[cfg(feature = "sprite")]
+fn bounds_from_sprite() {
+    use leafwing_2d::scale::Scale;
+
+    let mut app = App::new();
+    app.insert_resource(Time::default())
+        .add_plugin(TwoDPlugin::default());
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Position::<F32>::new(10.0, 10.0),
+            Scale::<F32>(2.0),
+            Sprite {
+                custom_size: Some(Vec2::new(4.0, 2.0)),
+                ..Default::default()
+            },
+            AxisAlignedBoundingBox::<F32>::default(),
+            LocalBoundingBox::<F32>::default(),
+            BoundsFromSprite,
+        ))
+        .id();
+
+    app.update();
+    assert_eq!(
+        *app.world
+            .get::<AxisAlignedBoundingBox<F32>>(entity)
+            .unwrap(),
+        AxisAlignedBoundingBox::new(6.0, 14.0, 8.0, 12.0)
+    );
+
+    app.world.get_mut::<Sprite>(entity).unwrap().custom_size = Some(Vec2::new(2.0, 2.0));
+    app.update();
+    assert_eq!(
+        *app.world
+            .get::<AxisAlignedBoundingBox<F32>>(entity)
+            .unwrap(),
+        AxisAlignedBoundingBox::new(8.0, 12.0, 8.0, 12.0)
+    );
+
+    // Without a custom size or a loaded texture, the bounds are left alone
+    app.world.get_mut::<Sprite>(entity).unwrap().custom_size = None;
+    app.update();
+    assert_eq!(
+        *app.world
+            .get::<AxisAlignedBoundingBox<F32>>(entity)
+            .unwrap(),
+        AxisAlignedBoundingBox::new(8.0, 12.0, 8.0, 12.0)
+    );
+}
+
+#[test]
+fn spatial_hash_tracks_bounds() {
+    let mut app = App::new();
+    app.insert_resource(Time::default())
+        .insert_resource(SpatialHash::<F32>::new(4.0))
+        .add_plugin(TwoDPlugin::default());
+
+    let box_entity = app
+        .world
+        .spawn()
+        .insert(AxisAlignedBoundingBox::<F32>::from_size(
+            Position::new(0.0, 0.0),
+            1.0,
+            1.0,
+        ))
+        .id();
+    let circle_entity = app
+        .world
+        .spawn()
+        .insert(BoundingCircle::<F32>::new(Position::new(1.5, 0.0), 1.0))
+        .id();
+    let far_entity = app
+        .world
+        .spawn()
+        .insert(BoundingCircle::<F32>::new(Position::new(100.0, 100.0), 1.0))
+        .id();
+
+    app.update();
+    let spatial_hash = app.world.resource::<SpatialHash<F32>>();
+    assert_eq!(spatial_hash.len(), 3);
+    assert_eq!(
+        spatial_hash.potential_pairs(),
+        vec![(box_entity, circle_entity)]
+    );
+    assert_eq!(
+        spatial_hash.query_region(&AxisAlignedBoundingBox::new(90.0, 110.0, 90.0, 110.0)),
+        vec![far_entity]
+    );
+
+    // Moving the circle away separates the pair
+    app.world
+        .get_mut::<BoundingCircle<F32>>(circle_entity)
+        .unwrap()
+        .center = Position::new(-50.0, 0.0);
+    app.update();
+    assert!(app
+        .world
+        .resource::<SpatialHash<F32>>()
+        .potential_pairs()
+        .is_empty());
+
+    // Despawned entities are removed
+    app.world.despawn(far_entity);
+    app.update();
+    let spatial_hash = app.world.resource::<SpatialHash<F32>>();
+    assert_eq!(spatial_hash.len(), 2);
+    assert!(spatial_hash.get(far_entity).is_none());
+}
+
+#[test]
+fn spatial_hash_handles_oversized_bounds() {
+    let mut spatial_hash = SpatialHash::<F32>::new(1.0);
+    let small = Entity::from_raw(0);
+    let huge = Entity::from_raw(1);
+    let unbounded = Entity::from_raw(2);
+    let far = Entity::from_raw(3);
+
+    spatial_hash.insert(
+        small,
+        AxisAlignedBoundingBox::from_size(Position::new(0.0, 0.0), 0.5, 0.5),
+    );
+    // Would cover a trillion cells
+    spatial_hash.insert(huge, AxisAlignedBoundingBox::new(-1e6, 1e6, -1e6, 1e6));
+    spatial_hash.insert(
+        unbounded,
+        AxisAlignedBoundingBox::new(0.0, f32::INFINITY, f32::NEG_INFINITY, 0.0),
+    );
+    spatial_hash.insert(
+        far,
+        AxisAlignedBoundingBox::from_size(Position::new(-5e5, 5e5), 0.5, 0.5),
+    );
+
+    assert_eq!(spatial_hash.len(), 4);
+    assert_eq!(
+        spatial_hash.potential_pairs(),
+        vec![
+            (small, huge),
+            (small, unbounded),
+            (huge, unbounded),
+            (huge, far)
+        ]
+    );
+    assert_eq!(
+        spatial_hash.query_point(Position::new(100.0, -100.0)),
+        vec![huge, unbounded]
+    );
+    // Querying a huge region checks every entity rather than every cell
+    assert_eq!(
+        spatial_hash.query_region(&AxisAlignedBoundingBox::new(-1e9, 1e9, 1.0, 1e9)),
+        vec![huge, far]
+    );
+
+    assert_eq!(
+        spatial_hash.remove(huge),
+        Some(AxisAlignedBoundingBox::new(-1e6, 1e6, -1e6, 1e6))
+    );
+    assert_eq!(spatial_hash.potential_pairs(), vec![(small, unbounded)]);
+}
+
+#[test]
+fn quadtree_queries() {
+    let world_bounds = AxisAlignedBoundingBox::<F32>::new(-64.0, 64.0, -64.0, 64.0);
+    let mut quadtree = Quadtree::new(world_bounds, 4, 2);
+
+    // A dense cluster in one corner, and a lone entity in the other
+    for i in 0..10 {
+        let position = Position::new(40.0 + i as f32 * 2.0, 40.0);
+        quadtree.insert(
+            Entity::from_raw(i),
+            AxisAlignedBoundingBox::from_size(position, 0.5, 0.5),
+        );
+    }
+    let loner = Entity::from_raw(100);
+    quadtree.insert(
+        loner,
+        AxisAlignedBoundingBox::from_size(Position::new(-40.0, -40.0), 0.5, 0.5),
+    );
+    assert_eq!(quadtree.len(), 11);
+
+    assert_eq!(
+        quadtree.query_region(&AxisAlignedBoundingBox::new(39.0, 44.0, 39.0, 41.0)),
+        vec![
+            Entity::from_raw(0),
+            Entity::from_raw(1),
+            Entity::from_raw(2)
+        ]
+    );
+    assert_eq!(
+        quadtree.query_point(Position::new(-40.0, -40.0)),
+        vec![loner]
+    );
+    assert_eq!(
+        quadtree.nearest(Position::new(-10.0, -10.0)).unwrap().0,
+        loner
+    );
+
+    // Updating moves the entity
+    quadtree.update(
+        loner,
+        AxisAlignedBoundingBox::from_size(Position::new(60.0, 60.0), 0.5, 0.5),
+    );
+    assert!(quadtree.query_point(Position::new(-40.0, -40.0)).is_empty());
+    assert_eq!(
+        quadtree.nearest(Position::new(64.0, 60.0)),
+        Some((loner, F32(3.5)))
+    );
+
+    // Entities outside of the root are still found
+    let outsider = Entity::from_raw(200);
+    quadtree.insert(
+        outsider,
+        AxisAlignedBoundingBox::from_size(Position::new(500.0, 0.0), 1.0, 1.0),
+    );
+    assert_eq!(
+        quadtree.query_point(Position::new(500.0, 0.0)),
+        vec![outsider]
+    );
+
+    for i in 0..10 {
+        assert!(quadtree.remove(Entity::from_raw(i)).is_some());
+    }
+    assert_eq!(quadtree.len(), 2);
+    assert!(quadtree.remove(Entity::from_raw(0)).is_none());
+    assert_eq!(
+        quadtree.query_region(&AxisAlignedBoundingBox::new(
+            -1000.0, 1000.0, -1000.0, 1000.0
+        )),
+        vec![loner, outsider]
+    );
+}
+
+#[test]
+fn quadtree_resource_follows_position() {
+    let mut app = App::new();
+    app.insert_resource(Time::default())
+        .insert_resource(Quadtree::<F32>::new(
+            AxisAlignedBoundingBox::new(-100.0, 100.0, -100.0, 100.0),
+            4,
+            4,
+        ))
+        .add_plugin(TwoDPlugin::default());
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Position::<F32>::new(10.0, 10.0),
+            AxisAlignedBoundingBox::<F32>::default(),
+            LocalBoundingBox::<F32>::from_size(1.0, 1.0),
+        ))
+        .id();
+
+    app.update();
+    assert_eq!(
+        app.world
+            .resource::<Quadtree<F32>>()
+            .query_point(Position::new(10.0, 10.0)),
+        vec![entity]
+    );
+
+    *app.world.get_mut::<Position<F32>>(entity).unwrap() = Position::new(-50.0, -50.0);
+    app.update();
+    let quadtree = app.world.resource::<Quadtree<F32>>();
+    assert!(quadtree.query_point(Position::new(10.0, 10.0)).is_empty());
+    assert_eq!(
+        quadtree.query_point(Position::new(-50.0, -50.0)),
+        vec![entity]
+    );
+}
+
+#[test]
+fn quadtree_resource_tracks_all_shapes() {
+    let mut app = App::new();
+    app.insert_resource(Time::default())
+        .insert_resource(Quadtree::<F32>::new(
+            AxisAlignedBoundingBox::new(-100.0, 100.0, -100.0, 100.0),
+            4,
+            4,
+        ))
+        .add_plugin(TwoDPlugin::default());
+
+    let circle = app
+        .world
+        .spawn()
+        .insert(BoundingCircle::<F32>::new(Position::new(20.0, 0.0), 1.0))
+        .id();
+    let collider = app
+        .world
+        .spawn()
+        .insert(Collider::from(BoundingPolygon::<F32>::new([
+            Position::new(-20.0, 0.0),
+            Position::new(-18.0, 0.0),
+            Position::new(-20.0, 2.0),
+        ])))
+        .id();
+
+    app.update();
+    let quadtree = app.world.resource::<Quadtree<F32>>();
+    assert_eq!(quadtree.len(), 2);
+    assert_eq!(quadtree.query_point(Position::new(20.5, 0.0)), vec![circle]);
+    assert_eq!(
+        quadtree.query_point(Position::new(-19.5, 0.5)),
+        vec![collider]
+    );
+
+    app.world.entity_mut(collider).remove::<Collider<F32>>();
+    app.update();
+    let quadtree = app.world.resource::<Quadtree<F32>>();
+    assert_eq!(quadtree.len(), 1);
+    assert!(quadtree.get(collider).is_none());
+}
+
+#[test]
+fn bounding_volume_hierarchy_queries() {
+    // A row of walls, plus one far above the rest
+    let walls = (0..16).map(|i| {
+        (
+            Entity::from_raw(i),
+            AxisAlignedBoundingBox::<F32>::new(i as f32 * 4.0, i as f32 * 4.0 + 1.0, 0.0, 2.0),
+        )
+    });
+    let mut bvh = BoundingVolumeHierarchy::build(walls.chain([(
+        Entity::from_raw(100),
+        AxisAlignedBoundingBox::new(0.0, 64.0, 50.0, 51.0),
+    )]));
+    assert_eq!(bvh.len(), 17);
+    assert_eq!(
+        bvh.bounds(),
+        Some(&AxisAlignedBoundingBox::new(0.0, 64.0, 0.0, 51.0))
+    );
+
+    assert_eq!(
+        bvh.query_region(&AxisAlignedBoundingBox::new(3.5, 8.5, 1.0, 1.0)),
+        vec![Entity::from_raw(1), Entity::from_raw(2)]
+    );
+    assert_eq!(
+        bvh.query_point(Position::new(10.0, 50.5)),
+        vec![Entity::from_raw(100)]
+    );
+    assert!(bvh.query_point(Position::new(2.0, 1.0)).is_empty());
+
+    let ray = Ray::new(Position::new(2.0, 1.0), Direction::EAST);
+    let (entity, hit) = bvh.cast_ray(ray).unwrap();
+    assert_eq!(entity, Entity::from_raw(1));
+    assert_eq!(hit.distance, F32(2.0));
+    assert_eq!(hit.normal, Direction::WEST);
+    assert_eq!(bvh.cast_ray_all(ray).len(), 15);
+
+    let (entity, _) = bvh
+        .cast_ray(Ray::new(Position::new(2.0, 1.0), Direction::NORTH))
+        .unwrap();
+    assert_eq!(entity, Entity::from_raw(100));
+
+    // Moving a wall out of the way lets the ray through to the next one
+    assert!(bvh.refit(
+        Entity::from_raw(1),
+        AxisAlignedBoundingBox::new(4.0, 5.0, -20.0, -18.0)
+    ));
+    let (entity, _) = bvh.cast_ray(ray).unwrap();
+    assert_eq!(entity, Entity::from_raw(2));
+    assert_eq!(
+        bvh.bounds(),
+        Some(&AxisAlignedBoundingBox::new(0.0, 64.0, -20.0, 51.0))
+    );
+
+    assert!(!bvh.refit(Entity::from_raw(1000), AxisAlignedBoundingBox::default()));
+}
+
+#[test]
+fn bounding_volume_hierarchy_resource_refits() {
+    let mut app = App::new();
+    app.insert_resource(Time::default())
+        .add_plugin(TwoDPlugin::default());
+
+    let wall = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Position::<F32>::new(10.0, 10.0),
+            AxisAlignedBoundingBox::<F32>::default(),
+            LocalBoundingBox::<F32>::from_size(1.0, 1.0),
+        ))
+        .id();
+    app.update();
+
+    let walls: Vec<(Entity, AxisAlignedBoundingBox<F32>)> = app
+        .world
+        .query::<(Entity, &AxisAlignedBoundingBox<F32>)>()
+        .iter(&app.world)
+        .map(|(entity, aabb)| (entity, aabb.clone()))
+        .collect();
+    app.insert_resource(BoundingVolumeHierarchy::build(walls));
+
+    *app.world.get_mut::<Position<F32>>(wall).unwrap() = Position::new(-10.0, 0.0);
+    app.update();
+
+    let bvh = app.world.resource::<BoundingVolumeHierarchy<F32>>();
+    assert!(bvh.query_point(Position::new(10.0, 10.0)).is_empty());
+    assert_eq!(bvh.query_point(Position::new(-10.0, 0.0)), vec![wall]);
+}
+
+#[derive(Clone, Copy)]
+enum Layer {
+    Player,
+    Enemy,
+    PlayerBullet,
+}
+
+impl CollisionLayer for Layer {
+    fn index(self) -> u32 {
+        self as u32
+    }
+}
+
+#[test]
+fn collision_layers() {
+    let player = CollisionLayers::from_layers([Layer::Player], [Layer::Enemy]);
+    let bullet = CollisionLayers::from_layers([Layer::PlayerBullet], [Layer::Enemy]);
+    let enemy = CollisionLayers::from_layers([Layer::Enemy], [Layer::Player, Layer::PlayerBullet]);
+
+    assert!(player.interacts_with(&enemy));
+    assert!(enemy.interacts_with(&bullet));
+    assert!(!player.interacts_with(&bullet));
+    assert!(!enemy.interacts_with(&enemy));
+    assert!(CollisionLayers::ALL.interacts_with(&player));
+    assert!(!CollisionLayers::NONE.interacts_with(&CollisionLayers::ALL));
+
+    assert!(player.contains_membership(Layer::Player));
+    assert!(!player.contains_filter(Layer::PlayerBullet));
+    assert_eq!(
+        player
+            .with_filter(Layer::PlayerBullet)
+            .without_filter(Layer::PlayerBullet),
+        player
+    );
+    assert_eq!(CollisionLayers::default(), CollisionLayers::ALL);
+}
+
+#[test]
+fn spatial_indexes_honor_layers() {
+    let player = Entity::from_raw(0);
+    let bullet = Entity::from_raw(1);
+    let player_layers = CollisionLayers::from_layers([Layer::Player], [Layer::Enemy]);
+    let bullet_layers = CollisionLayers::from_layers([Layer::PlayerBullet], [Layer::Enemy]);
+    let enemy_layers =
+        CollisionLayers::from_layers([Layer::Enemy], [Layer::Player, Layer::PlayerBullet]);
+
+    let player_box = AxisAlignedBoundingBox::<F32>::from_size(Position::new(0.0, 0.0), 1.0, 1.0);
+    let bullet_box = AxisAlignedBoundingBox::<F32>::from_size(Position::new(0.5, 0.0), 1.0, 1.0);
+
+    let mut spatial_hash = SpatialHash::new(4.0);
+    spatial_hash.insert(player, player_box.clone());
+    spatial_hash.insert(bullet, bullet_box.clone());
+    assert_eq!(spatial_hash.potential_pairs(), vec![(player, bullet)]);
+
+    assert!(spatial_hash.set_layers(player, player_layers));
+    assert!(spatial_hash.set_layers(bullet, bullet_layers));
+    assert!(spatial_hash.potential_pairs().is_empty());
+    assert_eq!(
+        spatial_hash.query_point_with_layers(Position::new(0.0, 0.0), &bullet_layers),
+        vec![]
+    );
+    assert_eq!(
+        spatial_hash.query_point_with_layers(Position::new(0.0, 0.0), &enemy_layers),
+        vec![player, bullet]
+    );
+
+    let mut quadtree = Quadtree::new(AxisAlignedBoundingBox::new(-8.0, 8.0, -8.0, 8.0), 4, 1);
+    quadtree.insert(player, player_box.clone());
+    quadtree.insert(bullet, bullet_box.clone());
+    assert!(quadtree.set_layers(player, player_layers));
+    assert!(quadtree.set_layers(bullet, bullet_layers));
+    assert!(!quadtree.set_layers(Entity::from_raw(99), player_layers));
+    assert_eq!(
+        quadtree.nearest_with_layers(Position::new(3.0, 0.0), &player_layers),
+        None
+    );
+    assert_eq!(
+        quadtree
+            .nearest_with_layers(Position::new(3.0, 0.0), &enemy_layers)
+            .map(|(entity, _)| entity),
+        Some(bullet)
+    );
+    // Layers survive updates to the bounding box
+    quadtree.update(bullet, player_box.clone());
+    assert_eq!(quadtree.layers(bullet), bullet_layers);
+
+    let mut bvh = BoundingVolumeHierarchy::build([(player, player_box), (bullet, bullet_box)]);
+    assert!(bvh.set_layers(bullet, bullet_layers));
+    let ray = Ray::new(Position::new(10.0, 0.0), Direction::WEST);
+    assert_eq!(bvh.cast_ray(ray).map(|(entity, _)| entity), Some(bullet));
+    assert_eq!(
+        bvh.cast_ray_with_layers(ray, &player_layers)
+            .map(|(entity, _)| entity),
+        Some(player)
+    );
+    assert_eq!(bvh.cast_ray_all_with_layers(ray, &player_layers).len(), 1);
+}
+
+#[test]
+fn raycast_query_honors_layers() {
+    let mut world = World::new();
+    let player_layers = CollisionLayers::from_layers([Layer::Player], [Layer::Enemy]);
+    let bullet_layers = CollisionLayers::from_layers([Layer::PlayerBullet], [Layer::Enemy]);
+
+    let shield = world
+        .spawn()
+        .insert_bundle((
+            BoundingCircle::<F32>::new(Position::new(5.0, 0.0), 1.0),
+            CollisionLayers::from_layers([Layer::Player], [Layer::Enemy]),
+        ))
+        .id();
+    let enemy = world
+        .spawn()
+        .insert_bundle((
+            BoundingCircle::<F32>::new(Position::new(10.0, 0.0), 1.0),
+            CollisionLayers::from_layers([Layer::Enemy], [Layer::Player, Layer::PlayerBullet]),
+        ))
+        .id();
+
+    let mut system_state: SystemState<RaycastQuery<F32>> = SystemState::new(&mut world);
+    let raycast = system_state.get_mut(&mut world);
+    let ray = Ray::new(Position::default(), Direction::EAST);
+
+    assert_eq!(raycast.cast_ray(ray).unwrap().0, shield);
+    // The player's own bullets pass through their shield
+    assert_eq!(
+        raycast.cast_ray_with_layers(ray, &bullet_layers).unwrap().0,
+        enemy
+    );
+    assert!(raycast.cast_ray_with_layers(ray, &player_layers).is_some());
+    assert_eq!(
+        raycast.cast_ray_all_with_layers(ray, &bullet_layers).len(),
+        1
+    );
+}
+
+#[test]
+fn continuous_collision_honors_layers() {
+    let mut app = App::new();
+    app.insert_resource(Time::default()).add_plugin(TwoDPlugin {
+        fixed_timestep: Some(Duration::from_millis(10)),
+        ..Default::default()
+    });
+
+    let bullet = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Position::<F32>::default(),
+            Velocity::<F32> {
+                x: F32(1000.0),
+                y: F32(1000.0),
+            },
+            Acceleration::<F32>::default(),
+            AxisAlignedBoundingBox::<F32>::from_size(Position::default(), 1.0, 1.0),
+            CollisionLayers::from_layers([Layer::PlayerBullet], [Layer::Enemy]),
+            ContinuousCollision,
+        ))
+        .id();
+
+    // The player is in the way, but bullets pass through them
+    app.world.spawn().insert_bundle((
+        AxisAlignedBoundingBox::<F32>::from_size(Position::new(10.0, 10.0), 1.0, 1.0),
+        CollisionLayers::from_layers([Layer::Player], [Layer::Enemy]),
+    ));
+
+    // Three steps of 10 units each carry the bullet through the player
+    app.world
+        .resource_mut::<FixedTimestep>()
+        .accumulate(Duration::from_millis(30));
+    app.update();
+
+    let position = *app.world.get::<Position<F32>>(bullet).unwrap();
+    position.assert_approx_eq(Position::<F32>::new(30.0, 30.0));
+}
+
+#[derive(Component)]
+struct Enemy;
+
+fn check_spatial_queries(mut app: App) {
+    app.insert_resource(Time::default())
+        .add_plugin(TwoDPlugin::default());
+
+    let spawn = |app: &mut App, x: f32, y: f32| {
+        app.world
+            .spawn()
+            .insert_bundle((
+                Position::<F32>::new(x, y),
+                AxisAlignedBoundingBox::<F32>::default(),
+                LocalBoundingBox::<F32>::from_size(0.5, 0.5),
+                Enemy,
+            ))
+            .id()
+    };
+    let near = spawn(&mut app, 3.0, 4.0);
+    let nearer = spawn(&mut app, 1.0, 0.0);
+    let far = spawn(&mut app, 60.0, -80.0);
+    let also_near = spawn(&mut app, 3.0, 4.0);
+    // Not an enemy, so it should never be found
+    app.world.spawn().insert_bundle((
+        Position::<F32>::new(1.0, 0.0),
+        AxisAlignedBoundingBox::<F32>::default(),
+        LocalBoundingBox::<F32>::from_size(0.5, 0.5),
+    ));
+    app.update();
+
+    let mut system_state: SystemState<SpatialQuery<F32, With<Enemy>>> =
+        SystemState::new(&mut app.world);
+    let spatial_query = system_state.get_mut(&mut app.world);
+
+    assert_eq!(
+        spatial_query.entities_at(Position::new(3.0, 4.0)),
+        vec![near, also_near]
+    );
+    assert!(spatial_query
+        .entities_at(Position::new(3.0, 3.0))
+        .is_empty());
+
+    assert_eq!(
+        spatial_query.entities_within(Position::default(), 5.0),
+        vec![(nearer, F32(1.0)), (near, F32(5.0)), (also_near, F32(5.0))]
+    );
+    assert!(spatial_query
+        .entities_within(Position::new(-20.0, 0.0), 5.0)
+        .is_empty());
+
+    assert_eq!(
+        spatial_query.k_nearest(Position::default(), 2),
+        vec![(nearer, F32(1.0)), (near, F32(5.0))]
+    );
+    assert_eq!(
+        spatial_query
+            .k_nearest(Position::new(50.0, -50.0), 10)
+            .into_iter()
+            .map(|(entity, _)| entity)
+            .collect::<Vec<_>>(),
+        vec![far, nearer, near, also_near]
+    );
+    assert!(spatial_query.k_nearest(Position::default(), 0).is_empty());
+
+    assert_eq!(
+        spatial_query.entities_in_region(&AxisAlignedBoundingBox::new(0.0, 3.0, 0.0, 4.0)),
+        vec![near, nearer, also_near]
+    );
+    assert_eq!(
+        spatial_query.entities_in_region(&AxisAlignedBoundingBox::new(50.0, 70.0, -90.0, -70.0)),
+        vec![far]
+    );
+}
+
+#[test]
+fn spatial_query_scans_positions() {
+    check_spatial_queries(App::new());
+}
+
+#[test]
+fn spatial_query_uses_spatial_hash() {
+    let mut app = App::new();
+    app.insert_resource(SpatialHash::<F32>::new(2.0));
+    check_spatial_queries(app);
+}
+
+#[test]
+fn spatial_query_uses_quadtree() {
+    let mut app = App::new();
+    app.insert_resource(Quadtree::<F32>::new(
+        AxisAlignedBoundingBox::new(-100.0, 100.0, -100.0, 100.0),
+        6,
+        1,
+    ));
+    check_spatial_queries(app);
+}
+
+fn check_spatial_queries_ignore_layers(mut app: App) {
+    app.insert_resource(Time::default())
+        .add_plugin(TwoDPlugin::default());
+
+    let spawn = |app: &mut App, x: f32, layers: CollisionLayers| {
+        app.world
+            .spawn()
+            .insert_bundle((
+                Position::<F32>::new(x, 0.0),
+                AxisAlignedBoundingBox::<F32>::default(),
+                LocalBoundingBox::<F32>::from_size(0.5, 0.5),
+                layers,
+                Enemy,
+            ))
+            .id()
+    };
+    let ghost = spawn(&mut app, 1.0, CollisionLayers::NONE);
+    let enemy = spawn(&mut app, 2.0, CollisionLayers::ALL);
+    app.update();
+
+    let mut system_state: SystemState<SpatialQuery<F32, With<Enemy>>> =
+        SystemState::new(&mut app.world);
+    let spatial_query = system_state.get_mut(&mut app.world);
+
+    assert_eq!(
+        spatial_query.entities_at(Position::new(1.0, 0.0)),
+        vec![ghost]
+    );
+    assert_eq!(
+        spatial_query.entities_within(Position::default(), 5.0),
+        vec![(ghost, F32(1.0)), (enemy, F32(2.0))]
+    );
+    assert_eq!(
+        spatial_query.entities_in_region(&AxisAlignedBoundingBox::new(0.0, 3.0, -1.0, 1.0)),
+        vec![ghost, enemy]
+    );
+    // Asking for more entities than exist must not search forever
+    assert_eq!(
+        spatial_query.k_nearest(Position::default(), 100),
+        vec![(ghost, F32(1.0)), (enemy, F32(2.0))]
+    );
+}
+
+#[test]
+fn spatial_query_ignores_layers_with_spatial_hash() {
+    let mut app = App::new();
+    app.insert_resource(SpatialHash::<F32>::new(2.0));
+    check_spatial_queries_ignore_layers(app);
+}
+
+#[test]
+fn spatial_query_ignores_layers_with_quadtree() {
+    let mut app = App::new();
+    app.insert_resource(Quadtree::<F32>::new(
+        AxisAlignedBoundingBox::new(-100.0, 100.0, -100.0, 100.0),
+        6,
+        1,
+    ));
+    check_spatial_queries_ignore_layers(app);
+}
@@ -0,0 +1,556 @@
+use bevy::ecs::event::Events;
+use bevy::prelude::*;
+use leafwing_2d::bounding::*;
+use leafwing_2d::bundles::TwoDBundle;
+use leafwing_2d::collision::*;
+use leafwing_2d::continuous::F32;
+use leafwing_2d::kinematics::{Acceleration, Velocity};
+use leafwing_2d::plugin::{TwoDPlugin, TwoDSystem};
+use leafwing_2d::position::Position;
+use leafwing_2d::trigger::*;
+
+fn collision_app() -> App {
+    let mut app = App::new();
+    app.insert_resource(Time::default())
+        .add_plugin(TwoDPlugin::default())
+        .add_plugin(CollisionPlugin::default());
+    app
+}
+
+fn drain_events<E: 'static + Send + Sync>(app: &mut App) -> Vec<E> {
+    app.world.resource_mut::<Events<E>>().drain().collect()
+}
+
+fn move_circle(app: &mut App, entity: Entity, x: f32) {
+    app.world
+        .get_mut::<BoundingCircle<F32>>(entity)
+        .unwrap()
+        .center = Position::new(x, 0.0);
+}
+
+#[test]
+fn collision_lifecycle() {
+    let mut app = collision_app();
+
+    let wall = app
+        .world
+        .spawn()
+        .insert(AxisAlignedBoundingBox::<F32>::new(-1.0, 1.0, -5.0, 5.0))
+        .id();
+    let ball = app
+        .world
+        .spawn()
+        .insert(BoundingCircle::<F32>::new(Position::new(-10.0, 0.0), 1.0))
+        .id();
+
+    app.update();
+    assert!(drain_events::<CollisionStarted>(&mut app).is_empty());
+    assert!(app.world.resource::<ActiveCollisions>().is_empty());
+
+    move_circle(&mut app, ball, -1.5);
+    app.update();
+    assert_eq!(
+        drain_events::<CollisionStarted>(&mut app),
+        vec![CollisionStarted(wall, ball)]
+    );
+    assert!(drain_events::<CollisionOngoing>(&mut app).is_empty());
+    assert!(app
+        .world
+        .resource::<ActiveCollisions>()
+        .contains(ball, wall));
+
+    move_circle(&mut app, ball, 0.0);
+    app.update();
+    assert!(drain_events::<CollisionStarted>(&mut app).is_empty());
+    assert_eq!(
+        drain_events::<CollisionOngoing>(&mut app),
+        vec![CollisionOngoing(wall, ball)]
+    );
+
+    move_circle(&mut app, ball, 10.0);
+    app.update();
+    assert_eq!(
+        drain_events::<CollisionEnded>(&mut app),
+        vec![CollisionEnded(wall, ball)]
+    );
+    assert!(app.world.resource::<ActiveCollisions>().is_empty());
+}
+
+#[test]
+fn circles_near_box_corners_do_not_collide() {
+    let mut app = collision_app();
+
+    app.world
+        .spawn()
+        .insert(AxisAlignedBoundingBox::<F32>::new(0.0, 2.0, 0.0, 2.0));
+    // The bounding boxes overlap, but the circle itself misses the corner
+    app.world
+        .spawn()
+        .insert(BoundingCircle::<F32>::new(Position::new(2.8, 2.8), 1.0));
+
+    app.update();
+    assert!(drain_events::<CollisionStarted>(&mut app).is_empty());
+}
+
+#[test]
+fn colliders_collide_with_other_shapes() {
+    let mut app = collision_app();
+
+    let wall = app
+        .world
+        .spawn()
+        .insert(Collider::from(AxisAlignedBoundingBox::<F32>::new(
+            -1.0, 1.0, -5.0, 5.0,
+        )))
+        .id();
+    let ball = app
+        .world
+        .spawn()
+        .insert(BoundingCircle::<F32>::new(Position::new(-1.5, 0.0), 1.0))
+        .id();
+    let triangle = app
+        .world
+        .spawn()
+        .insert(Collider::from(BoundingPolygon::<F32>::new([
+            Position::new(1.0, 4.0),
+            Position::new(3.0, 4.0),
+            Position::new(3.0, 6.0),
+        ])))
+        .id();
+    // Far away from everything else
+    app.world
+        .spawn()
+        .insert(Collider::from(BoundingCircle::<F32>::new(
+            Position::new(20.0, 20.0),
+            1.0,
+        )));
+
+    app.update();
+    assert_eq!(
+        drain_events::<CollisionStarted>(&mut app),
+        vec![
+            CollisionStarted(wall, ball),
+            CollisionStarted(wall, triangle)
+        ]
+    );
+}
+
+#[test]
+fn despawning_ends_collisions() {
+    let mut app = collision_app();
+
+    let a = app
+        .world
+        .spawn()
+        .insert(BoundingPolygon::<F32>::new([
+            Position::new(0.0, 0.0),
+            Position::new(2.0, 0.0),
+            Position::new(0.0, 2.0),
+        ]))
+        .id();
+    let b = app
+        .world
+        .spawn()
+        .insert(BoundingCircle::<F32>::new(Position::new(0.5, 0.5), 0.1))
+        .id();
+
+    app.update();
+    assert_eq!(
+        drain_events::<CollisionStarted>(&mut app),
+        vec![CollisionStarted(a, b)]
+    );
+
+    app.world.despawn(b);
+    app.update();
+    assert_eq!(
+        drain_events::<CollisionEnded>(&mut app),
+        vec![CollisionEnded(a, b)]
+    );
+}
+
+#[test]
+fn empty_bounding_boxes_never_collide() {
+    let mut app = collision_app();
+    app.insert_resource(SpatialHash::<F32>::new(4.0));
+
+    // Every bundle starts with a zero-sized bounding box at the origin
+    for _ in 0..3 {
+        app.world
+            .spawn()
+            .insert_bundle(TwoDBundle::<F32>::default());
+    }
+    let wall = app
+        .world
+        .spawn()
+        .insert(AxisAlignedBoundingBox::<F32>::new(-1.0, 1.0, -1.0, 1.0))
+        .id();
+    let ball = app
+        .world
+        .spawn()
+        .insert_bundle(TwoDBundle::<F32>::default())
+        .insert(BoundingCircle::<F32>::new(Position::new(1.5, 0.0), 1.0))
+        .id();
+
+    app.update();
+    assert_eq!(
+        drain_events::<CollisionStarted>(&mut app),
+        vec![CollisionStarted(wall, ball)]
+    );
+
+    app.world.remove_resource::<SpatialHash<F32>>();
+    app.update();
+    assert_eq!(
+        drain_events::<CollisionOngoing>(&mut app),
+        vec![CollisionOngoing(wall, ball)]
+    );
+}
+
+#[test]
+fn spatial_hash_broadphase() {
+    let mut app = collision_app();
+    app.insert_resource(SpatialHash::<F32>::new(4.0));
+
+    let entities: Vec<Entity> = (0..10)
+        .map(|i| {
+            app.world
+                .spawn()
+                .insert(BoundingCircle::<F32>::new(
+                    Position::new(i as f32 * 1.5, 0.0),
+                    1.0,
+                ))
+                .id()
+        })
+        .collect();
+
+    app.update();
+    let started = drain_events::<CollisionStarted>(&mut app);
+    let expected: Vec<CollisionStarted> = entities
+        .windows(2)
+        .map(|pair| CollisionStarted(pair[0], pair[1]))
+        .collect();
+    assert_eq!(started, expected);
+}
+
+#[test]
+fn collision_layers_filter_events() {
+    let mut app = collision_app();
+
+    let a = app
+        .world
+        .spawn()
+        .insert_bundle((
+            BoundingCircle::<F32>::new(Position::new(0.0, 0.0), 1.0),
+            CollisionLayers::new(0b01, 0b10),
+        ))
+        .id();
+    let b = app
+        .world
+        .spawn()
+        .insert_bundle((
+            BoundingCircle::<F32>::new(Position::new(0.5, 0.0), 1.0),
+            CollisionLayers::new(0b01, 0b10),
+        ))
+        .id();
+
+    app.update();
+    assert!(drain_events::<CollisionStarted>(&mut app).is_empty());
+
+    // Changing layers lets the entities collide
+    *app.world.get_mut::<CollisionLayers>(b).unwrap() = CollisionLayers::new(0b10, 0b01);
+    app.update();
+    assert_eq!(
+        drain_events::<CollisionStarted>(&mut app),
+        vec![CollisionStarted(a, b)]
+    );
+}
+
+#[test]
+fn collision_layers_filter_spatial_hash_pairs() {
+    let mut app = collision_app();
+    app.insert_resource(SpatialHash::<F32>::new(4.0));
+
+    app.world.spawn().insert_bundle((
+        BoundingCircle::<F32>::new(Position::new(0.0, 0.0), 1.0),
+        CollisionLayers::NONE,
+    ));
+    app.world
+        .spawn()
+        .insert(BoundingCircle::<F32>::new(Position::new(0.5, 0.0), 1.0));
+
+    app.update();
+    assert!(drain_events::<CollisionStarted>(&mut app).is_empty());
+}
+
+#[test]
+fn trigger_zone_lifecycle() {
+    let mut app = collision_app();
+
+    let zone = app
+        .world
+        .spawn()
+        .insert(TriggerZone::<_>::new(AxisAlignedBoundingBox::<F32>::new(
+            0.0, 4.0, 0.0, 4.0,
+        )))
+        .id();
+    let player = app
+        .world
+        .spawn()
+        .insert(Position::<F32>::new(-1.0, 2.0))
+        .id();
+
+    app.update();
+    assert!(drain_events::<TriggerEntered>(&mut app).is_empty());
+
+    *app.world.get_mut::<Position<F32>>(player).unwrap() = Position::new(3.0, 2.0);
+    app.update();
+    assert_eq!(
+        drain_events::<TriggerEntered>(&mut app),
+        vec![TriggerEntered {
+            zone,
+            entity: player
+        }]
+    );
+    let trigger_zone = app
+        .world
+        .get::<TriggerZone<AxisAlignedBoundingBox<F32>>>(zone)
+        .unwrap();
+    assert!(trigger_zone.contains(player));
+    assert_eq!(trigger_zone.occupants().collect::<Vec<_>>(), vec![player]);
+
+    // Staying inside the zone does not send further events
+    app.update();
+    assert!(drain_events::<TriggerEntered>(&mut app).is_empty());
+    assert!(drain_events::<TriggerExited>(&mut app).is_empty());
+
+    app.world.despawn(player);
+    app.update();
+    assert_eq!(
+        drain_events::<TriggerExited>(&mut app),
+        vec![TriggerExited {
+            zone,
+            entity: player
+        }]
+    );
+    assert!(app
+        .world
+        .get::<TriggerZone<AxisAlignedBoundingBox<F32>>>(zone)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn trigger_zones_honor_layers() {
+    let mut app = collision_app();
+
+    let zone = app
+        .world
+        .spawn()
+        .insert_bundle((
+            TriggerZone::<_>::new(BoundingCircle::<F32>::new(Position::new(0.0, 0.0), 2.0)),
+            CollisionLayers::new(0b01, 0b01),
+        ))
+        .id();
+    let player = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Position::<F32>::new(1.0, 0.0),
+            CollisionLayers::new(0b01, 0b01),
+        ))
+        .id();
+    app.world.spawn().insert_bundle((
+        Position::<F32>::new(1.0, 0.0),
+        CollisionLayers::new(0b10, 0b10),
+    ));
+
+    app.update();
+    assert_eq!(
+        drain_events::<TriggerEntered>(&mut app),
+        vec![TriggerEntered {
+            zone,
+            entity: player
+        }]
+    );
+}
+
+#[derive(Component)]
+struct Player;
+
+#[test]
+fn trigger_zones_filter_by_component() {
+    let mut app = collision_app();
+    app.add_system_to_stage(
+        CoreStage::PostUpdate,
+        update_trigger_zones::<BoundingCircle<F32>, With<Player>>
+            .label(TwoDSystem::TriggerZones)
+            .after(TwoDSystem::Kinematics),
+    );
+
+    let zone = app
+        .world
+        .spawn()
+        .insert(TriggerZone::<_, With<Player>>::new(
+            BoundingCircle::<F32>::new(Position::new(0.0, 0.0), 2.0),
+        ))
+        .id();
+    let player = app
+        .world
+        .spawn()
+        .insert_bundle((Position::<F32>::new(1.0, 0.0), Player))
+        .id();
+    app.world.spawn().insert(Position::<F32>::new(-1.0, 0.0));
+
+    app.update();
+    assert_eq!(
+        drain_events::<TriggerEntered>(&mut app),
+        vec![TriggerEntered {
+            zone,
+            entity: player
+        }]
+    );
+
+    // Losing the required component counts as leaving the zone
+    app.world.entity_mut(player).remove::<Player>();
+    app.update();
+    assert_eq!(
+        drain_events::<TriggerExited>(&mut app),
+        vec![TriggerExited {
+            zone,
+            entity: player
+        }]
+    );
+}
+
+fn spawn_mover(
+    app: &mut App,
+    position: Position<F32>,
+    velocity: Velocity<F32>,
+    response: CollisionResponse,
+) -> Entity {
+    app.world
+        .spawn()
+        .insert_bundle((
+            position,
+            velocity,
+            Acceleration::<F32>::default(),
+            AxisAlignedBoundingBox::<F32>::default(),
+            LocalBoundingBox::<F32>::from_size(1.0, 1.0),
+            response,
+        ))
+        .id()
+}
+
+#[test]
+fn collision_response_slides_along_walls() {
+    let mut app = collision_app();
+
+    // A wall whose left face is at x = 0
+    app.world.spawn().insert_bundle((
+        AxisAlignedBoundingBox::<F32>::new(0.0, 4.0, -10.0, 10.0),
+        StaticCollider,
+    ));
+    let player = spawn_mover(
+        &mut app,
+        Position::new(-0.5, 0.0),
+        Velocity {
+            x: F32(3.0),
+            y: F32(2.0),
+        },
+        CollisionResponse::SLIDE,
+    );
+
+    app.update();
+    assert_eq!(
+        *app.world.get::<Position<F32>>(player).unwrap(),
+        Position::new(-1.0, 0.0)
+    );
+    assert_eq!(
+        *app.world.get::<Velocity<F32>>(player).unwrap(),
+        Velocity {
+            x: F32(0.0),
+            y: F32(2.0)
+        }
+    );
+    assert_eq!(
+        *app.world
+            .get::<AxisAlignedBoundingBox<F32>>(player)
+            .unwrap(),
+        AxisAlignedBoundingBox::new(-2.0, 0.0, -1.0, 1.0)
+    );
+}
+
+#[test]
+fn collision_response_bounces() {
+    let mut app = collision_app();
+
+    // A floor whose top face is at y = 0
+    app.world.spawn().insert_bundle((
+        AxisAlignedBoundingBox::<F32>::new(-10.0, 10.0, -4.0, 0.0),
+        StaticCollider,
+    ));
+    let ball = spawn_mover(
+        &mut app,
+        Position::new(0.0, 0.75),
+        Velocity {
+            x: F32(1.0),
+            y: F32(-4.0),
+        },
+        CollisionResponse::new(0.5),
+    );
+    // Entities moving away from obstacles keep their velocity
+    let rising = spawn_mover(
+        &mut app,
+        Position::new(5.0, 0.75),
+        Velocity {
+            x: F32(0.0),
+            y: F32(4.0),
+        },
+        CollisionResponse::new(0.5),
+    );
+
+    app.update();
+    assert_eq!(
+        *app.world.get::<Position<F32>>(ball).unwrap(),
+        Position::new(0.0, 1.0)
+    );
+    assert_eq!(
+        *app.world.get::<Velocity<F32>>(ball).unwrap(),
+        Velocity {
+            x: F32(1.0),
+            y: F32(2.0)
+        }
+    );
+    assert_eq!(
+        *app.world.get::<Velocity<F32>>(rising).unwrap(),
+        Velocity {
+            x: F32(0.0),
+            y: F32(4.0)
+        }
+    );
+}
+
+#[test]
+fn collision_response_honors_layers() {
+    let mut app = collision_app();
+
+    app.world.spawn().insert_bundle((
+        AxisAlignedBoundingBox::<F32>::new(0.0, 4.0, -10.0, 10.0),
+        StaticCollider,
+        CollisionLayers::new(0b01, 0b01),
+    ));
+    let ghost = spawn_mover(
+        &mut app,
+        Position::new(1.0, 0.0),
+        Velocity::default(),
+        CollisionResponse::SLIDE,
+    );
+    app.world
+        .entity_mut(ghost)
+        .insert(CollisionLayers::new(0b10, 0b10));
+
+    app.update();
+    assert_eq!(
+        *app.world.get::<Position<F32>>(ghost).unwrap(),
+        Position::new(1.0, 0.0)
+    );
+}
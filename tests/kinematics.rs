@@ -0,0 +1,666 @@
+use bevy::prelude::*;
+use core::time::Duration;
+use leafwing_2d::bounding::ContinuousCollision;
+use leafwing_2d::bundles::TwoDBundle;
+use leafwing_2d::continuous::F32;
+use leafwing_2d::kinematics::systems::{
+    angular_kinematics, clear_forces, continuous_linear_kinematics, linear_kinematics,
+};
+use leafwing_2d::kinematics::{
+    Acceleration, AccelerationField, AngularAcceleration, AngularDamping, AngularImpulse,
+    AngularVelocity, Damping, Force, Impulse, Integrator, LinearDamping, Mass, MaxAcceleration,
+    MaxAccelerationPerAxis, MaxAngularVelocity, MaxSpeed, MaxSpeedPerAxis, MomentOfInertia, Torque,
+    Velocity,
+};
+use leafwing_2d::orientation::Rotation;
+use leafwing_2d::plugin::{TwoDPlugin, TwoDSystem};
+use leafwing_2d::position::{Position, Positionlike};
+use leafwing_2d::timestep::FixedTimestep;
+
+const INTEGRATORS: [Integrator; 4] = [
+    Integrator::ExplicitEuler,
+    Integrator::SemiImplicitEuler,
+    Integrator::VelocityVerlet,
+    Integrator::RungeKutta4,
+];
+
+/// Simulates a body for `steps` steps, returning the largest change in its energy along the way
+fn energy_drift(
+    integrator: Integrator,
+    (mut position, mut velocity): (Position<F32>, Velocity<F32>),
+    acceleration: Acceleration<F32>,
+    field: Option<&AccelerationField<F32>>,
+    energy: impl Fn(Position<F32>, Velocity<F32>) -> f32,
+    delta_time: Duration,
+    steps: usize,
+) -> f32 {
+    let initial_energy = energy(position, velocity);
+    let mut drift: f32 = 0.;
+
+    for _ in 0..steps {
+        let (displacement, new_velocity) =
+            integrator.integrate(position, velocity, acceleration, field, delta_time);
+        position += displacement;
+        velocity = new_velocity;
+
+        drift = drift.max((energy(position, velocity) - initial_energy).abs());
+    }
+
+    drift
+}
+
+fn kinetic_energy(velocity: Velocity<F32>) -> f32 {
+    0.5 * (velocity.x.0 * velocity.x.0 + velocity.y.0 * velocity.y.0)
+}
+
+#[test]
+fn orbital_energy_drift() {
+    // A unit mass orbiting a point at the origin, with a gravitational parameter of 1
+    let gravity = AccelerationField::<F32>::new(|position, _velocity| {
+        let distance = (position.x.0 * position.x.0 + position.y.0 * position.y.0).sqrt();
+        let strength = -1. / distance.powi(3);
+
+        Acceleration {
+            x: F32(position.x.0 * strength),
+            y: F32(position.y.0 * strength),
+        }
+    });
+    let energy = |position: Position<F32>, velocity: Velocity<F32>| {
+        let distance = (position.x.0 * position.x.0 + position.y.0 * position.y.0).sqrt();
+
+        kinetic_energy(velocity) - 1. / distance
+    };
+
+    // A circular orbit, simulated for more than a full revolution
+    let drifts = INTEGRATORS.map(|integrator| {
+        energy_drift(
+            integrator,
+            (
+                Position::new(1.0, 0.0),
+                Velocity {
+                    x: F32(0.0),
+                    y: F32(1.0),
+                },
+            ),
+            Acceleration::default(),
+            Some(&gravity),
+            energy,
+            Duration::from_millis(10),
+            1000,
+        )
+    });
+    let [explicit_euler, semi_implicit_euler, velocity_verlet, runge_kutta_4] = drifts;
+
+    // Explicit Euler spirals outwards
+    assert!(explicit_euler > 0.05, "{drifts:?}");
+    assert!(semi_implicit_euler < 1e-3, "{drifts:?}");
+    assert!(velocity_verlet < 1e-4, "{drifts:?}");
+    assert!(runge_kutta_4 < 1e-4, "{drifts:?}");
+    assert!(explicit_euler > semi_implicit_euler, "{drifts:?}");
+    assert!(semi_implicit_euler > velocity_verlet, "{drifts:?}");
+}
+
+#[test]
+fn projectile_energy_drift() {
+    let gravity = Acceleration {
+        x: F32(0.0),
+        y: F32(-9.8),
+    };
+    let energy = |position: Position<F32>, velocity: Velocity<F32>| {
+        kinetic_energy(velocity) + 9.8 * position.y.0
+    };
+
+    // Four seconds of flight at 60 steps per second
+    let drifts = INTEGRATORS.map(|integrator| {
+        energy_drift(
+            integrator,
+            (
+                Position::default(),
+                Velocity {
+                    x: F32(3.0),
+                    y: F32(20.0),
+                },
+            ),
+            gravity,
+            None,
+            energy,
+            Duration::from_secs_f32(1. / 60.),
+            240,
+        )
+    });
+    let [explicit_euler, semi_implicit_euler, velocity_verlet, runge_kutta_4] = drifts;
+
+    // The first-order methods gain or lose a little energy every step
+    assert!(explicit_euler > 1., "{drifts:?}");
+    assert!(semi_implicit_euler > 1., "{drifts:?}");
+    // Constant acceleration is integrated exactly by the higher-order methods
+    assert!(velocity_verlet < 0.01, "{drifts:?}");
+    assert!(runge_kutta_4 < 0.01, "{drifts:?}");
+}
+
+#[test]
+fn integrators_agree_without_acceleration() {
+    let velocity = Velocity {
+        x: F32(2.0),
+        y: F32(-1.0),
+    };
+
+    for integrator in INTEGRATORS {
+        let (displacement, new_velocity) = integrator.integrate(
+            Position::new(5.0, 5.0),
+            velocity,
+            Acceleration::default(),
+            None,
+            Duration::from_millis(500),
+        );
+
+        displacement.assert_approx_eq(Position::<F32>::new(1.0, -0.5));
+        assert_eq!(new_velocity, velocity, "{integrator:?}");
+    }
+}
+
+#[test]
+fn angular_velocity_over_a_frame() {
+    let frame = Duration::from_secs_f32(1. / 60.);
+
+    assert_eq!(
+        AngularVelocity { deci_degrees: 600. } * frame,
+        Rotation::new(10)
+    );
+    assert_eq!(
+        AngularVelocity {
+            deci_degrees: -600.
+        } * frame,
+        Rotation::new(3590)
+    );
+    assert_eq!(
+        AngularAcceleration { deci_degrees: 30. } * Duration::from_millis(500),
+        AngularVelocity { deci_degrees: 15. }
+    );
+}
+
+#[test]
+fn slow_spins_accumulate() {
+    let mut app = App::new();
+    app.insert_resource(Time::default())
+        .insert_resource(FixedTimestep::from_steps_per_second(60.))
+        .add_system(angular_kinematics);
+
+    // Less than a tenth of a degree each frame
+    let clockwise = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Rotation::default(),
+            AngularVelocity { deci_degrees: 6. },
+            AngularAcceleration::default(),
+        ))
+        .id();
+    let counterclockwise = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Rotation::default(),
+            AngularVelocity { deci_degrees: -3. },
+            AngularAcceleration::default(),
+        ))
+        .id();
+
+    // One second, in fixed steps
+    for _ in 0..60 {
+        app.update();
+    }
+
+    assert_eq!(
+        *app.world.get::<Rotation>(clockwise).unwrap(),
+        Rotation::new(6)
+    );
+    assert_eq!(
+        *app.world.get::<Rotation>(counterclockwise).unwrap(),
+        Rotation::new(3597)
+    );
+}
+
+#[test]
+fn forces_and_impulses() {
+    let mut app = App::new();
+    app.insert_resource(Time::default())
+        .insert_resource(FixedTimestep::from_steps_per_second(10.))
+        .add_system(linear_kinematics::<F32>.label(TwoDSystem::Kinematics))
+        .add_system(angular_kinematics.label(TwoDSystem::Kinematics))
+        .add_system(clear_forces::<F32>.after(TwoDSystem::Kinematics));
+
+    let pushed = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Position::<F32>::default(),
+            Velocity::<F32>::default(),
+            Acceleration::<F32>::default(),
+            Mass(2.),
+            Force {
+                x: F32(4.),
+                y: F32(0.),
+            },
+            Impulse {
+                x: F32(0.),
+                y: F32(6.),
+            },
+        ))
+        .insert_bundle((
+            Rotation::default(),
+            AngularVelocity::default(),
+            AngularAcceleration::default(),
+            MomentOfInertia(4.),
+            Torque { deci_degrees: 400. },
+            AngularImpulse { deci_degrees: 40. },
+        ))
+        .id();
+    let immovable = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Position::<F32>::default(),
+            Velocity::<F32>::default(),
+            Acceleration::<F32>::default(),
+            Mass::INFINITE,
+            Impulse {
+                x: F32(100.),
+                y: F32(100.),
+            },
+        ))
+        .id();
+
+    app.update();
+
+    // The impulse is applied immediately, while the force accelerates the entity over the step
+    assert_eq!(
+        *app.world.get::<Velocity<F32>>(pushed).unwrap(),
+        Velocity {
+            x: F32(0.2),
+            y: F32(3.),
+        }
+    );
+    assert_eq!(
+        *app.world.get::<AngularVelocity>(pushed).unwrap(),
+        AngularVelocity { deci_degrees: 20. }
+    );
+    assert_eq!(
+        *app.world.get::<Rotation>(pushed).unwrap(),
+        Rotation::new(2)
+    );
+
+    // Forces and impulses are only applied once
+    assert_eq!(
+        *app.world.get::<Force<F32>>(pushed).unwrap(),
+        Force::default()
+    );
+    assert_eq!(
+        *app.world.get::<Impulse<F32>>(pushed).unwrap(),
+        Impulse::default()
+    );
+    assert_eq!(*app.world.get::<Torque>(pushed).unwrap(), Torque::default());
+    assert_eq!(
+        *app.world.get::<AngularImpulse>(pushed).unwrap(),
+        AngularImpulse::default()
+    );
+
+    app.update();
+    assert_eq!(
+        *app.world.get::<Velocity<F32>>(pushed).unwrap(),
+        Velocity {
+            x: F32(0.2),
+            y: F32(3.),
+        }
+    );
+    assert_eq!(
+        *app.world.get::<Velocity<F32>>(immovable).unwrap(),
+        Velocity::default()
+    );
+}
+
+#[test]
+fn forces_act_for_every_step_in_a_frame() {
+    let mut app = App::new();
+    app.insert_resource(Time::default()).add_plugin(TwoDPlugin {
+        fixed_timestep: Some(Duration::from_millis(10)),
+        ..Default::default()
+    });
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle(TwoDBundle::<F32>::default())
+        .insert_bundle((
+            Mass(2.),
+            Force {
+                x: F32(40.),
+                y: F32(0.),
+            },
+            MomentOfInertia(4.),
+            Torque { deci_degrees: 400. },
+        ))
+        .id();
+
+    // `Time` is never updated, so only the time added here is simulated
+    app.world
+        .resource_mut::<FixedTimestep>()
+        .accumulate(Duration::from_millis(30));
+    app.update();
+
+    // The force is applied in each of the three steps, and only cleared afterwards
+    let velocity = *app.world.get::<Velocity<F32>>(entity).unwrap();
+    assert_close((velocity.x.0, velocity.y.0), (0.6, 0.));
+    let angular_velocity = *app.world.get::<AngularVelocity>(entity).unwrap();
+    assert!((angular_velocity.deci_degrees - 3.).abs() < 1e-4);
+    assert_eq!(
+        *app.world.get::<Force<F32>>(entity).unwrap(),
+        Force::default()
+    );
+    assert_eq!(*app.world.get::<Torque>(entity).unwrap(), Torque::default());
+
+    // Forces set during a frame without any steps are still cleared, without being applied
+    *app.world.get_mut::<Force<F32>>(entity).unwrap() = Force {
+        x: F32(40.),
+        y: F32(0.),
+    };
+    app.update();
+
+    let velocity = *app.world.get::<Velocity<F32>>(entity).unwrap();
+    assert_close((velocity.x.0, velocity.y.0), (0.6, 0.));
+    assert_eq!(
+        *app.world.get::<Force<F32>>(entity).unwrap(),
+        Force::default()
+    );
+}
+
+#[test]
+fn non_positive_masses_are_immovable() {
+    let mut app = App::new();
+    app.insert_resource(Time::default())
+        .insert_resource(FixedTimestep::from_steps_per_second(10.))
+        .add_system(linear_kinematics::<F32>)
+        .add_system(angular_kinematics);
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Position::<F32>::default(),
+            Velocity::<F32>::default(),
+            Acceleration::<F32>::default(),
+            Mass(0.),
+            Force {
+                x: F32(4.),
+                y: F32(4.),
+            },
+            Impulse {
+                x: F32(4.),
+                y: F32(4.),
+            },
+        ))
+        .insert_bundle((
+            Rotation::default(),
+            AngularVelocity::default(),
+            AngularAcceleration::default(),
+            MomentOfInertia(-1.),
+            Torque { deci_degrees: 40. },
+            AngularImpulse { deci_degrees: 40. },
+        ))
+        .id();
+
+    app.update();
+
+    assert_eq!(
+        *app.world.get::<Velocity<F32>>(entity).unwrap(),
+        Velocity::default()
+    );
+    assert_eq!(
+        *app.world.get::<AngularVelocity>(entity).unwrap(),
+        AngularVelocity::default()
+    );
+}
+
+#[test]
+#[should_panic]
+fn mass_must_be_positive() {
+    let _ = Mass::new(0.);
+}
+
+/// Simulates one second of damped motion in steps of equal length, returning the final velocities
+fn damped_velocities(damping: Damping, steps_per_second: u32) -> (Velocity<F32>, AngularVelocity) {
+    let mut app = App::new();
+    app.insert_resource(Time::default())
+        .insert_resource(FixedTimestep::from_steps_per_second(
+            steps_per_second as f64,
+        ))
+        .add_system(linear_kinematics::<F32>)
+        .add_system(angular_kinematics);
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Position::<F32>::default(),
+            Velocity {
+                x: F32(30.),
+                y: F32(-40.),
+            },
+            Acceleration::<F32>::default(),
+            LinearDamping(damping),
+            Rotation::default(),
+            AngularVelocity { deci_degrees: -50. },
+            AngularAcceleration::default(),
+            AngularDamping(damping),
+        ))
+        .id();
+
+    for _ in 0..steps_per_second {
+        app.update();
+    }
+
+    (
+        *app.world.get::<Velocity<F32>>(entity).unwrap(),
+        *app.world.get::<AngularVelocity>(entity).unwrap(),
+    )
+}
+
+#[test]
+fn damping_is_frame_rate_independent() {
+    for (damping, expected_speed) in [
+        (Damping::Linear(10.), 40.),
+        (Damping::Quadratic(0.02), 25.),
+        (Damping::Exponential(2.0f32.ln()), 25.),
+    ] {
+        let (slow_velocity, slow_angular_velocity) = damped_velocities(damping, 10);
+        let (fast_velocity, fast_angular_velocity) = damped_velocities(damping, 100);
+
+        for velocity in [slow_velocity, fast_velocity] {
+            // The direction of motion is preserved
+            assert!(
+                (velocity.x.0 / velocity.y.0 + 0.75).abs() < 1e-4,
+                "{damping:?}"
+            );
+            let speed = velocity.x.0.hypot(velocity.y.0);
+            assert!(
+                (speed - expected_speed).abs() < 1e-3,
+                "{damping:?}: {speed}"
+            );
+        }
+
+        for angular_velocity in [slow_angular_velocity, fast_angular_velocity] {
+            assert!(angular_velocity.deci_degrees < 0., "{damping:?}");
+        }
+        assert!(
+            (slow_angular_velocity.deci_degrees - fast_angular_velocity.deci_degrees).abs() < 1e-3,
+            "{damping:?}"
+        );
+    }
+}
+
+#[test]
+fn linear_damping_stops_without_reversing() {
+    let damping = Damping::Linear(10.);
+
+    assert_eq!(damping.damp(5., Duration::from_secs(1)), 0.);
+    assert_eq!(damping.damp(-5., Duration::from_secs(1)), 0.);
+    assert_eq!(damping.damp(-15., Duration::from_secs(1)), -5.);
+}
+
+#[test]
+fn negative_damping_never_speeds_up() {
+    for damping in [
+        Damping::Linear(-10.),
+        Damping::Quadratic(-10.),
+        Damping::Exponential(-10.),
+    ] {
+        assert_eq!(damping.damp(5., Duration::from_secs(1)), 5.);
+        assert_eq!(damping.damp(-5., Duration::from_secs(1)), -5.);
+    }
+}
+
+fn assert_close(actual: (f32, f32), expected: (f32, f32)) {
+    assert!(
+        (actual.0 - expected.0).abs() < 1e-4 && (actual.1 - expected.1).abs() < 1e-4,
+        "{actual:?} was not close to {expected:?}"
+    );
+}
+
+#[test]
+fn speed_limits() {
+    let mut app = App::new();
+    app.insert_resource(Time::default())
+        .insert_resource(FixedTimestep::from_steps_per_second(10.))
+        .add_system(linear_kinematics::<F32>)
+        .add_system(angular_kinematics);
+
+    let spaceship = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Position::<F32>::default(),
+            Velocity::<F32>::default(),
+            Acceleration {
+                x: F32(300.),
+                y: F32(400.),
+            },
+            MaxSpeed(F32(10.)),
+        ))
+        .id();
+    let platformer = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Position::<F32>::default(),
+            Velocity {
+                x: F32(0.),
+                y: F32(-50.),
+            },
+            Acceleration {
+                x: F32(100.),
+                y: F32(-100.),
+            },
+            MaxSpeedPerAxis {
+                x: F32(5.),
+                y: F32(20.),
+            },
+            MaxAccelerationPerAxis {
+                x: F32(1.),
+                y: F32(1000.),
+            },
+        ))
+        .id();
+    let spinner = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Rotation::default(),
+            AngularVelocity { deci_degrees: 100. },
+            AngularAcceleration::default(),
+            MaxAngularVelocity { deci_degrees: 50. },
+        ))
+        .id();
+
+    app.update();
+
+    // The direction of motion is preserved
+    let velocity = *app.world.get::<Velocity<F32>>(spaceship).unwrap();
+    assert_close((velocity.x.0, velocity.y.0), (6., 8.));
+    let position = *app.world.get::<Position<F32>>(spaceship).unwrap();
+    assert_close((position.x.0, position.y.0), (0.6, 0.8));
+
+    // Each axis is limited separately
+    let velocity = *app.world.get::<Velocity<F32>>(platformer).unwrap();
+    assert_close((velocity.x.0, velocity.y.0), (0.1, -20.));
+    let position = *app.world.get::<Position<F32>>(platformer).unwrap();
+    assert_close((position.x.0, position.y.0), (0.01, -2.));
+
+    assert_eq!(
+        *app.world.get::<AngularVelocity>(spinner).unwrap(),
+        AngularVelocity { deci_degrees: 50. }
+    );
+    assert_eq!(
+        *app.world.get::<Rotation>(spinner).unwrap(),
+        Rotation::new(5)
+    );
+}
+
+#[test]
+fn continuous_collision_respects_limits() {
+    let mut app = App::new();
+    app.insert_resource(Time::default())
+        .insert_resource(FixedTimestep::from_steps_per_second(10.))
+        .add_system(continuous_linear_kinematics::<F32>);
+
+    let rocket = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Position::<F32>::default(),
+            Velocity::<F32>::default(),
+            Acceleration {
+                x: F32(300.),
+                y: F32(400.),
+            },
+            MaxAcceleration(F32(50.)),
+            ContinuousCollision,
+        ))
+        .id();
+    let platformer = app
+        .world
+        .spawn()
+        .insert_bundle((
+            Position::<F32>::default(),
+            Velocity {
+                x: F32(0.),
+                y: F32(-50.),
+            },
+            Acceleration {
+                x: F32(100.),
+                y: F32(-100.),
+            },
+            MaxSpeedPerAxis {
+                x: F32(5.),
+                y: F32(20.),
+            },
+            MaxAccelerationPerAxis {
+                x: F32(1.),
+                y: F32(1000.),
+            },
+            ContinuousCollision,
+        ))
+        .id();
+
+    app.update();
+
+    // Limits apply in the same way as with `linear_kinematics`
+    let velocity = *app.world.get::<Velocity<F32>>(rocket).unwrap();
+    assert_close((velocity.x.0, velocity.y.0), (3., 4.));
+    let position = *app.world.get::<Position<F32>>(rocket).unwrap();
+    assert_close((position.x.0, position.y.0), (0.3, 0.4));
+
+    let velocity = *app.world.get::<Velocity<F32>>(platformer).unwrap();
+    assert_close((velocity.x.0, velocity.y.0), (0.1, -20.));
+    let position = *app.world.get::<Position<F32>>(platformer).unwrap();
+    assert_close((position.x.0, position.y.0), (0.01, -2.));
+}
@@ -0,0 +1,133 @@
+#![cfg(feature = "picking")]
+
+use bevy::ecs::event::Events;
+use bevy::prelude::*;
+use leafwing_2d::bounding::*;
+use leafwing_2d::continuous::F32;
+use leafwing_2d::picking::*;
+use leafwing_2d::plugin::TwoDPlugin;
+use leafwing_2d::position::Position;
+
+fn picking_app() -> App {
+    let mut app = App::new();
+    app.insert_resource(Time::default())
+        .add_plugin(TwoDPlugin::default())
+        .add_plugin(PickingPlugin::default());
+    app
+}
+
+fn drain_events<E: 'static + Send + Sync>(app: &mut App) -> Vec<E> {
+    app.world.resource_mut::<Events<E>>().drain().collect()
+}
+
+fn move_cursor(app: &mut App, position: Option<Position<F32>>, just_pressed: bool) {
+    let mut cursor = app.world.resource_mut::<Cursor<F32>>();
+    cursor.position = position;
+    cursor.just_pressed = just_pressed;
+}
+
+#[test]
+fn hover_and_click() {
+    let mut app = picking_app();
+
+    let button = app
+        .world
+        .spawn()
+        .insert_bundle((
+            AxisAlignedBoundingBox::<F32>::new(0.0, 4.0, 0.0, 2.0),
+            Pickable,
+        ))
+        .id();
+    // Not pickable, so it can never be hovered
+    app.world
+        .spawn()
+        .insert(BoundingCircle::<F32>::new(Position::new(10.0, 0.0), 2.0));
+
+    move_cursor(&mut app, Some(Position::new(10.0, 0.0)), false);
+    app.update();
+    assert!(drain_events::<HoverStarted>(&mut app).is_empty());
+    assert_eq!(app.world.resource::<Cursor<F32>>().hovered(), None);
+
+    move_cursor(&mut app, Some(Position::new(3.0, 1.0)), false);
+    app.update();
+    assert_eq!(
+        drain_events::<HoverStarted>(&mut app),
+        vec![HoverStarted(button)]
+    );
+    assert!(drain_events::<Clicked>(&mut app).is_empty());
+    assert_eq!(app.world.resource::<Cursor<F32>>().hovered(), Some(button));
+
+    move_cursor(&mut app, Some(Position::new(3.0, 1.5)), true);
+    app.update();
+    assert!(drain_events::<HoverStarted>(&mut app).is_empty());
+    assert_eq!(drain_events::<Clicked>(&mut app), vec![Clicked(button)]);
+
+    // Each press is only handled once, even if nothing resets the cursor
+    app.update();
+    assert!(drain_events::<Clicked>(&mut app).is_empty());
+    assert!(!app.world.resource::<Cursor<F32>>().just_pressed);
+
+    move_cursor(&mut app, None, false);
+    app.update();
+    assert_eq!(
+        drain_events::<HoverEnded>(&mut app),
+        vec![HoverEnded(button)]
+    );
+    assert!(app
+        .world
+        .resource::<Cursor<F32>>()
+        .under_cursor()
+        .is_empty());
+}
+
+#[test]
+fn topmost_entity_is_picked() {
+    let mut app = picking_app();
+
+    let background = app
+        .world
+        .spawn()
+        .insert_bundle((
+            BoundingPolygon::<F32>::new([
+                Position::new(-10.0, -10.0),
+                Position::new(10.0, -10.0),
+                Position::new(0.0, 10.0),
+            ]),
+            GlobalTransform::from_xyz(0.0, 0.0, 0.0),
+            Pickable,
+        ))
+        .id();
+    let foreground = app
+        .world
+        .spawn()
+        .insert_bundle((
+            BoundingCircle::<F32>::new(Position::new(0.0, 0.0), 2.0),
+            GlobalTransform::from_xyz(0.0, 0.0, 5.0),
+            Pickable,
+        ))
+        .id();
+
+    move_cursor(&mut app, Some(Position::new(1.0, 1.0)), true);
+    app.update();
+    assert_eq!(
+        app.world.resource::<Cursor<F32>>().under_cursor(),
+        &[foreground, background]
+    );
+    assert_eq!(
+        drain_events::<HoverStarted>(&mut app),
+        vec![HoverStarted(foreground)]
+    );
+    assert_eq!(drain_events::<Clicked>(&mut app), vec![Clicked(foreground)]);
+
+    // Moving off the foreground entity uncovers the background
+    move_cursor(&mut app, Some(Position::new(0.0, 5.0)), false);
+    app.update();
+    assert_eq!(
+        drain_events::<HoverEnded>(&mut app),
+        vec![HoverEnded(foreground)]
+    );
+    assert_eq!(
+        drain_events::<HoverStarted>(&mut app),
+        vec![HoverStarted(background)]
+    );
+}
@@ -2,6 +2,8 @@ use bevy::prelude::*;
 use core::fmt::Debug;
 use leafwing_2d::orientation::Direction;
 use leafwing_2d::prelude::*;
+use leafwing_2d::timestep::{FixedTimestep, Interpolated};
+use std::time::Duration;
 
 trait AppExtension {
     fn assert_component_eq<C: Component + PartialEq + Debug>(&mut self, value: &C);
@@ -187,3 +189,127 @@ fn sync_position() {
     app.update();
     app.assert_positionlike_approx_eq(Transform::from_xyz(4.0, 4.0, 5.0));
 }
+
+#[test]
+fn fixed_timestep_interpolation() {
+    let step = Duration::from_millis(10);
+
+    let mut app = App::new();
+    app.insert_resource(Time::default()).add_plugin(TwoDPlugin {
+        fixed_timestep: Some(step),
+        ..Default::default()
+    });
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle(TwoDBundle::<F32>::default())
+        .insert_bundle((
+            Transform::default(),
+            Velocity {
+                x: F32(100.0),
+                y: F32(0.0),
+            },
+        ))
+        .insert(Interpolated::<F32>::default())
+        .id();
+
+    // `Time` is never updated, so only the time added here is simulated
+    app.update();
+    app.assert_positionlike_approx_eq(Position::<F32>::default());
+
+    app.world
+        .resource_mut::<FixedTimestep>()
+        .accumulate(Duration::from_millis(25));
+    app.update();
+
+    // Only whole steps are simulated, and the remainder is carried over
+    let fixed_timestep = app.world.resource::<FixedTimestep>();
+    assert_eq!(fixed_timestep.accumulated(), Duration::from_millis(5));
+    assert!((fixed_timestep.overstep_fraction() - 0.5).abs() < 1e-4);
+
+    let position = *app.world.get::<Position<F32>>(entity).unwrap();
+    position.assert_approx_eq(Position::<F32>::new(2.0, 0.0));
+
+    // Rendering lags behind the simulation by the unsimulated fraction of a step
+    let transform = *app.world.get::<Transform>(entity).unwrap();
+    transform
+        .translation
+        .assert_approx_eq(Position::<F32>::new(1.5, 0.0));
+
+    // After a long stall, only a limited number of steps are run, and the rest of the time is dropped
+    app.world
+        .resource_mut::<FixedTimestep>()
+        .accumulate(Duration::from_secs(10));
+    app.update();
+
+    let fixed_timestep = app.world.resource::<FixedTimestep>();
+    assert_eq!(fixed_timestep.accumulated(), Duration::ZERO);
+    let max_steps = FixedTimestep::DEFAULT_MAX_STEPS_PER_FRAME as f32;
+    app.assert_positionlike_approx_eq(Position::<F32>::new(2.0 + max_steps, 0.0));
+}
+
+#[test]
+fn several_fixed_steps_per_frame() {
+    let mut app = App::new();
+    app.insert_resource(Time::default()).add_plugin(TwoDPlugin {
+        fixed_timestep: Some(Duration::from_millis(10)),
+        ..Default::default()
+    });
+    let interpolated = app
+        .world
+        .spawn()
+        .insert_bundle(TwoDBundle::<F32>::default())
+        .insert_bundle((
+            Transform::default(),
+            Acceleration {
+                x: F32(1000.0),
+                y: F32(0.0),
+            },
+        ))
+        .insert(Interpolated::<F32>::default())
+        .id();
+    let synced = app
+        .world
+        .spawn()
+        .insert_bundle(TwoDBundle::<F32>::default())
+        .insert_bundle((
+            Transform::default(),
+            Velocity {
+                x: F32(100.0),
+                y: F32(0.0),
+            },
+        ))
+        .id();
+
+    // `Time` is never updated, so only the time added here is simulated
+    app.update();
+    app.world
+        .resource_mut::<FixedTimestep>()
+        .accumulate(Duration::from_millis(35));
+    app.update();
+
+    // Three steps are run: the velocity is 10, 20 and then 30, so the entity moves to 0.1, 0.3 and then 0.6
+    let position = *app.world.get::<Position<F32>>(interpolated).unwrap();
+    position.assert_approx_eq(Position::<F32>::new(0.6, 0.0));
+    let previous_position = app
+        .world
+        .get::<Interpolated<F32>>(interpolated)
+        .unwrap()
+        .previous_position;
+    previous_position.assert_approx_eq(Position::<F32>::new(0.3, 0.0));
+
+    // The transform is interpolated within the last step, halfway between its previous and current position
+    let transform = *app.world.get::<Transform>(interpolated).unwrap();
+    assert!(transform.translation.x >= 0.3 && transform.translation.x <= 0.6);
+    transform
+        .translation
+        .assert_approx_eq(Position::<F32>::new(0.45, 0.0));
+
+    // Entities that are not interpolated are synced with their final position
+    let position = *app.world.get::<Position<F32>>(synced).unwrap();
+    position.assert_approx_eq(Position::<F32>::new(3.0, 0.0));
+    let transform = *app.world.get::<Transform>(synced).unwrap();
+    transform
+        .translation
+        .assert_approx_eq(Position::<F32>::new(3.0, 0.0));
+}
@@ -0,0 +1,86 @@
+#![cfg(feature = "camera")]
+
+use bevy::math::Vec2;
+use bevy::prelude::{GlobalTransform, OrthographicProjection, Transform};
+use bevy::render::camera::{Camera, CameraProjection};
+use leafwing_2d::continuous::F32;
+use leafwing_2d::position::{Position, Positionlike};
+use leafwing_2d::screen::Viewport;
+
+fn camera_with_scale(scale: f32, viewport_size: Vec2) -> Camera {
+    let mut projection = OrthographicProjection {
+        scale,
+        ..Default::default()
+    };
+    projection.update(viewport_size.x, viewport_size.y);
+
+    Camera {
+        projection_matrix: projection.get_projection_matrix(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn screen_space_round_trip() {
+    let viewport = Viewport::new(Vec2::ZERO, Vec2::new(800., 600.));
+    let camera = camera_with_scale(2., viewport.size);
+    let camera_transform = GlobalTransform::from_xyz(100., -50., 999.);
+
+    // Zooming out doubles the world-space distance covered by each pixel
+    Position::<F32>::from_screen_space(
+        Vec2::new(400., 300.),
+        &viewport,
+        &camera,
+        &camera_transform,
+    )
+    .unwrap()
+    .assert_approx_eq(Position::<F32>::new(100., -50.));
+    Position::<F32>::from_screen_space(Vec2::new(0., 0.), &viewport, &camera, &camera_transform)
+        .unwrap()
+        .assert_approx_eq(Position::<F32>::new(-700., -650.));
+
+    let position = Position::<F32>::new(140., -10.);
+    let screen_position = position.to_screen_space(&viewport, &camera, &camera_transform);
+    screen_position.assert_approx_eq(Vec2::new(420., 320.));
+    Position::<F32>::from_screen_space(screen_position, &viewport, &camera, &camera_transform)
+        .unwrap()
+        .assert_approx_eq(position);
+
+    // Transforms can be converted too, for placing UI over entities
+    Transform::from_xyz(140., -10., 5.)
+        .to_screen_space(&viewport, &camera, &camera_transform)
+        .assert_approx_eq(screen_position);
+}
+
+#[test]
+fn screen_space_accounts_for_viewport_and_rotation() {
+    // The camera only renders to the right half of an 800x600 window
+    let viewport = Viewport::new(Vec2::new(400., 0.), Vec2::new(400., 600.));
+    let camera = camera_with_scale(1., viewport.size);
+    let camera_transform = GlobalTransform::from(Transform::from_rotation(
+        bevy::math::Quat::from_rotation_z(core::f32::consts::FRAC_PI_2),
+    ));
+
+    assert_eq!(
+        Position::<F32>::from_screen_space(
+            Vec2::new(100., 300.),
+            &viewport,
+            &camera,
+            &camera_transform
+        ),
+        None
+    );
+
+    // The camera is rotated a quarter turn counterclockwise, so screen-space right is world-space up
+    Position::<F32>::from_screen_space(
+        Vec2::new(700., 300.),
+        &viewport,
+        &camera,
+        &camera_transform,
+    )
+    .unwrap()
+    .assert_approx_eq(Position::<F32>::new(0., 100.));
+    Position::<F32>::new(0., 100.)
+        .to_screen_space(&viewport, &camera, &camera_transform)
+        .assert_approx_eq(Vec2::new(700., 300.));
+}
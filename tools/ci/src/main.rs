@@ -9,8 +9,8 @@ fn main() {
         .run()
         .expect("Please run 'cargo fmt --all' to format your code.");
 
-    // Run tests
-    cmd!("cargo test")
+    // Run tests, including those for optional features
+    cmd!("cargo test --all-features")
         .run()
         .expect("Please fix failing tests in output above.");
 